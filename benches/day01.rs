@@ -0,0 +1,68 @@
+use std::hint::black_box;
+
+use aoc2023::day01::{part2, DIGITS};
+use aoc2023::testutil::XorShift;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const WORDS: [&str; 12] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "x", "qz", "ninex",
+];
+
+/// Calibration document of about `size` bytes, every line holding at least one digit.
+fn generate(size: usize) -> String {
+    let mut rng = XorShift::new(0x2545f4914f6cdd1d);
+    let mut next = |n: usize| rng.below(n as u64) as usize;
+
+    let mut input = String::with_capacity(size + 64);
+    while input.len() < size {
+        input.push(char::from(b'1' + next(9) as u8));
+        for _ in 0..next(8) + 2 {
+            match next(4) {
+                0 => input.push(char::from(b'1' + next(9) as u8)),
+                _ => input.push_str(WORDS[next(WORDS.len())]),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// Part 2 as it was before the automaton: one `match_indices` per spelling.
+fn part2_match_indices(input: &str) -> String {
+    input
+        .lines()
+        .map(|line| {
+            let digits = DIGITS
+                .iter()
+                .flat_map(|d| line.match_indices(d.code).map(|(i, _)| (i, d.value)))
+                .collect::<Vec<_>>();
+            digits.iter().min_by_key(|d| d.0).map(|d| d.1).unwrap() * 10
+                + digits.iter().max_by_key(|d| d.0).map(|d| d.1).unwrap()
+        })
+        .sum::<u32>()
+        .to_string()
+}
+
+fn bench_part2(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day01_part2");
+    for megabytes in [1, 4] {
+        let input = generate(megabytes << 20);
+        assert_eq!(part2(&input), part2_match_indices(&input));
+
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_function(format!("automaton/{}MB", megabytes), |b| {
+            b.iter(|| part2(black_box(&input)))
+        });
+        group.bench_function(format!("match_indices/{}MB", megabytes), |b| {
+            b.iter(|| part2_match_indices(black_box(&input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_part2
+}
+criterion_main!(benches);
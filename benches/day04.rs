@@ -0,0 +1,73 @@
+use std::hint::black_box;
+
+use aoc2023::day04::{parse_card, Card};
+use aoc2023::testutil::XorShift;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// `cards` scratchcards with 50 winning numbers and 200 own numbers each,
+/// drawn without repetition from `0..max`.
+fn generate(cards: usize, max: u32) -> String {
+    let mut rng = XorShift::new(0x9e3779b97f4a7c15);
+    let mut next = |n: u32| rng.below(n as u64) as u32;
+    let mut draw = |count: usize| {
+        let mut values = Vec::with_capacity(count);
+        while values.len() < count {
+            let n = next(max);
+            if !values.contains(&n) {
+                values.push(n);
+            }
+        }
+        values
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    (1..=cards)
+        .map(|id| format!("Card {}: {} | {}", id, draw(50), draw(200)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn scan_match_count(card: &Card) -> usize {
+    card.numbers()
+        .iter()
+        .filter(|n| card.winning_numbers().contains(n))
+        .count()
+}
+
+fn bench_match_count(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day04_match_count");
+    for (name, max) in [("bitset", 250), ("hashed", 100_000)] {
+        let cards = generate(5000, max)
+            .lines()
+            .map(|line| parse_card(line).unwrap().1)
+            .collect::<Vec<_>>();
+
+        group.bench_function(format!("{}/sets", name), |b| {
+            b.iter(|| {
+                black_box(&cards)
+                    .iter()
+                    .map(Card::match_count)
+                    .sum::<usize>()
+            })
+        });
+        group.bench_function(format!("{}/scan", name), |b| {
+            b.iter(|| {
+                black_box(&cards)
+                    .iter()
+                    .map(scan_match_count)
+                    .sum::<usize>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_match_count
+}
+criterion_main!(benches);
@@ -0,0 +1,103 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc2023::fixtures;
+use aoc2023::input::{self, InputStore};
+use aoc2023::registry::{Day, DAYS};
+use aoc2023::scaffold::Scaffold;
+use aoc2023::watch;
+
+const USAGE: &str = "Usage:
+    aoc new <day>
+    aoc fetch <day>
+    aoc run <day>
+    aoc fixtures <day> <puzzle.html>
+    aoc watch <day>";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let result = match args.as_slice() {
+        ["new", day] => new_day(day),
+        ["fetch", day] => fetch(day),
+        ["run", day] => run(day),
+        ["fixtures", day, page] => extract_fixtures(day, page),
+        ["watch", day] => watch_day(day),
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(day: &str) -> Result<(), String> {
+    let day = parse_day(day)?;
+    let created = Scaffold::new(env!("CARGO_MANIFEST_DIR"))
+        .create_day(day)
+        .map_err(|err| err.to_string())?;
+
+    for path in created {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(day: &str) -> Result<(), String> {
+    let day = parse_day(day)?;
+    let store = InputStore::from_env().map_err(|err| err.to_string())?;
+    store.get(day).map_err(|err| err.to_string())?;
+
+    println!("{}", store.path(day).display());
+    Ok(())
+}
+
+fn extract_fixtures(day: &str, page: &str) -> Result<(), String> {
+    let day = parse_day(day)?;
+    let html = fs::read_to_string(page).map_err(|err| format!("{}: {}", page, err))?;
+    let examples = fixtures::extract(&html);
+    if examples.is_empty() {
+        return Err(format!("{}: no puzzle description found", page));
+    }
+
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(format!("day{:02}", day));
+    for path in fixtures::write(&dir, &examples).map_err(|err| err.to_string())? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn run(day: &str) -> Result<(), String> {
+    let day = registered(parse_day(day)?)?;
+    let input = input::load(day.number);
+
+    println!("Part1: {}", (day.part1)(&input));
+    println!("Part2: {}", (day.part2)(&input));
+    Ok(())
+}
+
+fn watch_day(day: &str) -> Result<(), String> {
+    let day = registered(parse_day(day)?)?;
+
+    watch::watch(Path::new(env!("CARGO_MANIFEST_DIR")), day.number).map_err(|err| err.to_string())
+}
+
+fn registered(day: u8) -> Result<&'static Day, String> {
+    DAYS.iter()
+        .find(|d| d.number == day)
+        .ok_or_else(|| format!("day {} is not registered, run `aoc new {}`", day, day))
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse()
+        .map_err(|_| format!("invalid day '{}'\n{}", day, USAGE))
+}
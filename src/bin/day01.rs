@@ -1,152 +1,73 @@
-fn main() {
-    let input = include_str!("day01.in");
-
-    let part1_out = part1(input);
-    let part2_out = part2(input);
-
-    println!("Part1: {}", part1_out);
-    println!("Part2: {}", part2_out);
-}
+use std::env;
+use std::fs;
 
-fn part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|x| {
-            x.chars()
-                .find(|c| c.is_ascii_digit())
-                .map(|c| c.to_digit(10).unwrap())
-                .unwrap()
-                * 10
-                + x.chars()
-                    .rfind(|c| c.is_ascii_digit())
-                    .map(|c| c.to_digit(10).unwrap())
-                    .unwrap()
-        })
-        .sum::<u32>()
-        .to_string()
-}
-
-struct Digit {
-    code: &'static str,
-    value: u32,
-}
+use aoc2023::day01::{calibrate, explain, NoDigits, Vocabulary, BUILTIN};
 
-static DIGITS: [Digit; 18] = [
-    Digit {
-        code: "one",
-        value: 1,
-    },
-    Digit {
-        code: "two",
-        value: 2,
-    },
-    Digit {
-        code: "three",
-        value: 3,
-    },
-    Digit {
-        code: "four",
-        value: 4,
-    },
-    Digit {
-        code: "five",
-        value: 5,
-    },
-    Digit {
-        code: "six",
-        value: 6,
-    },
-    Digit {
-        code: "seven",
-        value: 7,
-    },
-    Digit {
-        code: "eight",
-        value: 8,
-    },
-    Digit {
-        code: "nine",
-        value: 9,
-    },
-    Digit {
-        code: "1",
-        value: 1,
-    },
-    Digit {
-        code: "2",
-        value: 2,
-    },
-    Digit {
-        code: "3",
-        value: 3,
-    },
-    Digit {
-        code: "4",
-        value: 4,
-    },
-    Digit {
-        code: "5",
-        value: 5,
-    },
-    Digit {
-        code: "6",
-        value: 6,
-    },
-    Digit {
-        code: "7",
-        value: 7,
-    },
-    Digit {
-        code: "8",
-        value: 8,
-    },
-    Digit {
-        code: "9",
-        value: 9,
-    },
-];
+const USAGE: &str = "Usage: day01 [--vocabulary <name|file>] [--explain]";
 
-fn part2(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| {
-            let digits = DIGITS
-                .iter()
-                .flat_map(|d| line.match_indices(d.code).map(|(i, _)| (i, d.value)))
-                .collect::<Vec<_>>();
-            digits.iter().min_by_key(|d| d.0).map(|d| d.1).unwrap() * 10
-                + digits.iter().max_by_key(|d| d.0).map(|d| d.1).unwrap()
-        })
-        .sum::<u32>()
-        .to_string()
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_part1() {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+fn main() {
+    let input = aoc2023::input::load(1);
 
-        let output = super::part1(input);
+    let mut vocabulary = None;
+    let mut explain_lines = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--vocabulary" => {
+                let name = args.next().unwrap_or_else(|| panic!("{}", USAGE));
+                vocabulary = Some((
+                    Vocabulary::builtin(&name).unwrap_or_else(|| load_vocabulary(&name)),
+                    name,
+                ));
+            }
+            "--explain" => explain_lines = true,
+            _ => panic!("{}", USAGE),
+        }
+    }
 
-        assert_eq!(output, "142")
+    if explain_lines {
+        let english = Vocabulary::english();
+        let vocabulary = vocabulary.as_ref().map_or(&english, |(v, _)| v);
+        let mut missing = 0;
+        for (i, explanation) in explain(&input, vocabulary).enumerate() {
+            println!("{:>5}: {}", i + 1, explanation.render());
+            if explanation.digits.is_none() {
+                missing += 1;
+            }
+        }
+        if missing > 0 {
+            println!("{} line(s) without digits", missing);
+        }
+        return;
     }
 
-    #[test]
-    fn test_part2() {
-        let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
+    if let Some((vocabulary, name)) = vocabulary {
+        report(
+            &format!("Calibration ({})", name),
+            calibrate(&input, &vocabulary),
+        );
+        return;
+    }
 
-        let output = super::part2(input);
+    report("Part1", calibrate(&input, &Vocabulary::ascii()));
+    report("Part2", calibrate(&input, &Vocabulary::english()));
+}
 
-        assert_eq!(output, "281")
+fn report(label: &str, result: Result<u32, NoDigits>) {
+    match result {
+        Ok(value) => println!("{}: {}", label, value),
+        Err(err) => println!("{}: {}, rerun with --explain", label, err),
     }
 }
+
+fn load_vocabulary(path: &str) -> Vocabulary {
+    let text = fs::read_to_string(path).unwrap_or_else(|err| {
+        panic!(
+            "{} is neither a built-in vocabulary ({}) nor a readable file: {}",
+            path,
+            BUILTIN.join(", "),
+            err
+        )
+    });
+    Vocabulary::parse(&text).unwrap_or_else(|err| panic!("{}: {}", path, err))
+}
@@ -0,0 +1,549 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+pub fn part1(input: &str) -> String {
+    calibrate(input, &Vocabulary::ascii())
+        .unwrap_or_else(|err| panic!("{}", err))
+        .to_string()
+}
+
+/// Spelling of a digit that may appear in a calibration line.
+pub struct Digit {
+    pub code: &'static str,
+    pub value: u32,
+}
+
+/// Digits recognized by part 2: English words and ASCII digits.
+pub static DIGITS: [Digit; 18] = [
+    Digit {
+        code: "one",
+        value: 1,
+    },
+    Digit {
+        code: "two",
+        value: 2,
+    },
+    Digit {
+        code: "three",
+        value: 3,
+    },
+    Digit {
+        code: "four",
+        value: 4,
+    },
+    Digit {
+        code: "five",
+        value: 5,
+    },
+    Digit {
+        code: "six",
+        value: 6,
+    },
+    Digit {
+        code: "seven",
+        value: 7,
+    },
+    Digit {
+        code: "eight",
+        value: 8,
+    },
+    Digit {
+        code: "nine",
+        value: 9,
+    },
+    Digit {
+        code: "1",
+        value: 1,
+    },
+    Digit {
+        code: "2",
+        value: 2,
+    },
+    Digit {
+        code: "3",
+        value: 3,
+    },
+    Digit {
+        code: "4",
+        value: 4,
+    },
+    Digit {
+        code: "5",
+        value: 5,
+    },
+    Digit {
+        code: "6",
+        value: 6,
+    },
+    Digit {
+        code: "7",
+        value: 7,
+    },
+    Digit {
+        code: "8",
+        value: 8,
+    },
+    Digit {
+        code: "9",
+        value: 9,
+    },
+];
+
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+const ROMAN: [&str; 9] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+/// Names accepted by [`Vocabulary::builtin`].
+pub const BUILTIN: [&str; 6] = ["digits", "en", "de", "fr", "es", "roman"];
+
+/// Spellings the calibration decoder recognizes, each with the value it stands for.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Vocabulary {
+    pub words: Vec<(String, u32)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Vocabulary {
+    /// ASCII digits `1`..`9` only.
+    pub fn ascii() -> Self {
+        Self::from_words(&["1", "2", "3", "4", "5", "6", "7", "8", "9"])
+    }
+
+    /// Part 2 vocabulary: English words and ASCII digits.
+    pub fn english() -> Self {
+        Self::from(&DIGITS[..])
+    }
+
+    /// One of [`BUILTIN`]; every language also keeps the ASCII digits.
+    ///
+    /// Roman numerals overlap like any other words: `VIII` reads as 8 when it
+    /// comes first on a line but its last digit is the final `I`.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let words = match name {
+            "digits" => return Some(Self::ascii()),
+            "en" => return Some(Self::english()),
+            "de" => &GERMAN,
+            "fr" => &FRENCH,
+            "es" => &SPANISH,
+            "roman" => &ROMAN,
+            _ => return None,
+        };
+        let mut vocabulary = Self::from_words(words);
+        vocabulary.words.extend(Self::ascii().words);
+        Some(vocabulary)
+    }
+
+    /// Reads `word value` pairs, one per line, where value is a digit `0`..`9`.
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |reason| ParseError {
+                line: i + 1,
+                reason,
+            };
+            let (word, value) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [word, value] => (word, value),
+                _ => return Err(error("expected `word value`")),
+            };
+            let value = value.parse().map_err(|_| error("value is not a number"))?;
+            if value > 9 {
+                return Err(error("value is not a single digit"));
+            }
+            words.push((word.to_string(), value));
+        }
+        if words.is_empty() {
+            return Err(ParseError {
+                line: 0,
+                reason: "no words defined",
+            });
+        }
+        Ok(Self { words })
+    }
+
+    fn from_words(words: &[&str]) -> Self {
+        Self {
+            words: (1..)
+                .zip(words)
+                .map(|(value, word)| (word.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+impl From<&[Digit]> for Vocabulary {
+    fn from(digits: &[Digit]) -> Self {
+        Self {
+            words: digits
+                .iter()
+                .map(|d| (d.code.to_string(), d.value))
+                .collect(),
+        }
+    }
+}
+
+/// Position of a digit spelling within a line, `start..end` in bytes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// Finds the first and the last digit of a line, each in a single scan.
+///
+/// Spellings may overlap, so in `eightwo` the first digit is 8 and the last is 2.
+pub struct Matcher {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl Matcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let words = &vocabulary.words;
+        Self {
+            forward: Automaton::new(words.iter().map(|(w, v)| (w.bytes().collect(), *v))),
+            backward: Automaton::new(words.iter().map(|(w, v)| (w.bytes().rev().collect(), *v))),
+        }
+    }
+
+    /// The match starting leftmost, the longest one if several start there.
+    pub fn first(&self, line: &str) -> Option<Match> {
+        self.forward.leftmost(line.bytes())
+    }
+
+    /// The match starting rightmost, the longest one if several start there.
+    pub fn last(&self, line: &str) -> Option<Match> {
+        // The rightmost start is the earliest end when scanning backwards.
+        self.backward.earliest(line.bytes().rev()).map(|m| Match {
+            start: line.len() - m.end,
+            end: line.len() - m.start,
+            value: m.value,
+        })
+    }
+}
+
+/// Aho-Corasick automaton with every transition precomputed.
+struct Automaton {
+    transitions: Vec<[u32; 256]>,
+    /// Longest pattern ending in each state as `(length, value)`.
+    outputs: Vec<Option<(usize, u32)>>,
+    max_len: usize,
+}
+
+impl Automaton {
+    fn new(patterns: impl Iterator<Item = (Vec<u8>, u32)>) -> Self {
+        let mut transitions = vec![[0; 256]];
+        let mut outputs = vec![None];
+        let mut max_len = 0;
+        for (pattern, value) in patterns {
+            let mut state = 0;
+            for &b in &pattern {
+                if transitions[state][b as usize] == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(None);
+                    transitions[state][b as usize] = (transitions.len() - 1) as u32;
+                }
+                state = transitions[state][b as usize] as usize;
+            }
+            if outputs[state].is_none() {
+                outputs[state] = Some((pattern.len(), value));
+            }
+            max_len = max_len.max(pattern.len());
+        }
+
+        // Breadth-first, so the failure state of every state is done before it.
+        let mut fail = vec![0; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .filter(|&&next| next != 0)
+            .map(|&next| next as usize)
+            .collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let fallback = fail[state];
+            if outputs[state].is_none() {
+                outputs[state] = outputs[fallback];
+            }
+            let fallback_row = transitions[fallback];
+            for (next, fallback_next) in transitions[state].iter_mut().zip(fallback_row) {
+                if *next == 0 {
+                    *next = fallback_next;
+                } else {
+                    fail[*next as usize] = fallback_next as usize;
+                    queue.push_back(*next as usize);
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            max_len,
+        }
+    }
+
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<Match> {
+        let mut state = 0;
+        let mut best: Option<Match> = None;
+        for (i, b) in bytes.enumerate() {
+            if best.is_some_and(|m| i >= m.start + self.max_len) {
+                // Nothing ending here or later can start before the best match.
+                break;
+            }
+            state = self.transitions[state][b as usize] as usize;
+            if let Some((len, value)) = self.outputs[state] {
+                let start = i + 1 - len;
+                if best.is_none_or(|m| start <= m.start) {
+                    best = Some(Match {
+                        start,
+                        end: i + 1,
+                        value,
+                    });
+                }
+            }
+        }
+        best
+    }
+
+    fn earliest(&self, bytes: impl Iterator<Item = u8>) -> Option<Match> {
+        let mut state = 0;
+        for (i, b) in bytes.enumerate() {
+            state = self.transitions[state][b as usize] as usize;
+            if let Some((len, value)) = self.outputs[state] {
+                return Some(Match {
+                    start: i + 1 - len,
+                    end: i + 1,
+                    value,
+                });
+            }
+        }
+        None
+    }
+}
+
+const FIRST_STYLE: &str = "\x1b[0;4;32m";
+const LAST_STYLE: &str = "\x1b[0;4;34m";
+const BOTH_STYLE: &str = "\x1b[0;4;36m";
+const RESET_STYLE: &str = "\x1b[0m";
+
+/// How a single line was decoded.
+#[derive(Debug, PartialEq)]
+pub struct Explanation<'a> {
+    pub line: &'a str,
+    /// First and last match, `None` when the line holds no digit.
+    pub digits: Option<(Match, Match)>,
+}
+
+impl Explanation<'_> {
+    pub fn value(&self) -> Option<u32> {
+        self.digits
+            .map(|(first, last)| first.value * 10 + last.value)
+    }
+
+    /// The line with the first digit in green and the last one in blue (cyan
+    /// where they overlap), followed by the tokens and the value.
+    pub fn render(&self) -> String {
+        let Some((first, last)) = self.digits else {
+            return format!("{}  !! no digits", self.line);
+        };
+
+        let mut out = String::new();
+        let mut current = None;
+        for (i, c) in self.line.char_indices() {
+            let in_first = (first.start..first.end).contains(&i);
+            let in_last = (last.start..last.end).contains(&i);
+            let style = match (in_first, in_last) {
+                (true, true) => Some(BOTH_STYLE),
+                (true, false) => Some(FIRST_STYLE),
+                (false, true) => Some(LAST_STYLE),
+                (false, false) => None,
+            };
+            if style != current {
+                out.push_str(style.unwrap_or(RESET_STYLE));
+                current = style;
+            }
+            out.push(c);
+        }
+        if current.is_some() {
+            out.push_str(RESET_STYLE);
+        }
+
+        format!(
+            "{}  => {} ({}@{}..{}, {}@{}..{})",
+            out,
+            self.value().unwrap(),
+            &self.line[first.start..first.end],
+            first.start,
+            first.end,
+            &self.line[last.start..last.end],
+            last.start,
+            last.end
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NoDigits {
+    /// 1-based line number.
+    pub line: usize,
+}
+
+impl fmt::Display for NoDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} has no digits", self.line)
+    }
+}
+
+impl std::error::Error for NoDigits {}
+
+pub fn explain<'a>(
+    input: &'a str,
+    vocabulary: &Vocabulary,
+) -> impl Iterator<Item = Explanation<'a>> {
+    let matcher = Matcher::new(vocabulary);
+    input.lines().map(move |line| Explanation {
+        line,
+        digits: matcher.first(line).zip(matcher.last(line)),
+    })
+}
+
+/// Sum of the calibration values of all lines: first digit * 10 + last digit.
+pub fn calibrate(input: &str, vocabulary: &Vocabulary) -> Result<u32, NoDigits> {
+    explain(input, vocabulary)
+        .enumerate()
+        .map(|(i, explanation)| explanation.value().ok_or(NoDigits { line: i + 1 }))
+        .sum()
+}
+
+pub fn part2(input: &str) -> String {
+    calibrate(input, &Vocabulary::english())
+        .unwrap_or_else(|err| panic!("{}", err))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use super::{NoDigits, ParseError, Vocabulary};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day01, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day01, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day01, part2);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day01, part2))
+    }
+
+    #[test]
+    fn test_overlapping_digits() {
+        let matcher = super::Matcher::new(&super::Vocabulary::english());
+
+        let first = matcher.first("xeightwo").unwrap();
+        let last = matcher.last("xeightwo").unwrap();
+
+        assert_eq!((first.start, first.end, first.value), (1, 6, 8));
+        assert_eq!((last.start, last.end, last.value), (5, 8, 2));
+        assert_eq!(matcher.first("oneight").unwrap().value, 1);
+        assert_eq!(matcher.last("oneight").unwrap().value, 8);
+        assert_eq!(matcher.first("abc"), None);
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let german = Vocabulary::builtin("de").unwrap();
+        let roman = Vocabulary::builtin("roman").unwrap();
+        let custom = Vocabulary::parse("# planets\nmercury 1\n\nuranus 7\n").unwrap();
+
+        assert_eq!(super::calibrate("xzweinsechs\nacht", &german), Ok(26 + 88));
+        assert_eq!(super::calibrate("aVIIIb\nIX4", &roman), Ok(81 + 94));
+        assert_eq!(super::calibrate("xmercuryuranus", &custom), Ok(17));
+        assert_eq!(
+            Vocabulary::parse("one\n"),
+            Err(ParseError {
+                line: 1,
+                reason: "expected `word value`"
+            })
+        );
+        assert_eq!(
+            Vocabulary::parse(
+                "one 1
+big 4294967295
+"
+            ),
+            Err(ParseError {
+                line: 2,
+                reason: "value is not a single digit"
+            })
+        );
+        assert_eq!(
+            Vocabulary::parse("ten 10"),
+            Err(ParseError {
+                line: 1,
+                reason: "value is not a single digit"
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_without_digits() {
+        let input = "1abc2\nxyz\n";
+
+        let output = super::calibrate(input, &Vocabulary::ascii());
+
+        assert_eq!(output, Err(NoDigits { line: 2 }))
+    }
+
+    #[test]
+    fn test_explain() {
+        let explained =
+            super::explain("xeightwo\nabc\n7", &Vocabulary::english()).collect::<Vec<_>>();
+
+        assert_eq!(explained[0].value(), Some(82));
+        assert_eq!(
+            explained[0].render(),
+            "x\x1b[0;4;32meigh\x1b[0;4;36mt\x1b[0;4;34mwo\x1b[0m  => 82 (eight@1..6, two@5..8)"
+        );
+        assert_eq!(explained[1].digits, None);
+        assert_eq!(explained[1].render(), "abc  !! no digits");
+        assert_eq!(explained[2].value(), Some(77));
+        assert_eq!(
+            super::calibrate("1\nabc", &Vocabulary::english()),
+            Err(NoDigits { line: 2 })
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use nom::branch::alt;
+use nom::character::complete::{alpha1, char, multispace0, space1, u32};
+use nom::combinator::{all_consuming, map, value};
+use nom::multi::{separated_list0, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair, tuple};
+use nom::{bytes::complete::tag, IResult};
+
+/// Number of cubes of each colour, e.g. shown in a single draw or held by a bag.
+pub type Cubes = BTreeMap<String, u32>;
+
+pub fn part1(input: &str) -> String {
+    let bag = Cubes::from([
+        ("red".to_string(), 12),
+        ("green".to_string(), 13),
+        ("blue".to_string(), 14),
+    ]);
+    input
+        .lines()
+        .map(|line| parse_game(line).unwrap().1)
+        .filter(|game| game.is_possible(&bag))
+        .map(|game| game.id)
+        .sum::<u32>()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let games = input
+        .lines()
+        .map(|line| parse_game(line).unwrap().1)
+        .collect::<Vec<_>>();
+    let colours = games
+        .iter()
+        .flat_map(|game| game.rounds.iter().flat_map(|r| r.keys()))
+        .collect::<BTreeSet<_>>();
+    games
+        .iter()
+        .map(|game| {
+            let bag = game.minimum_bag();
+            colours
+                .iter()
+                .map(|&c| bag.get(c).copied().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum::<u32>()
+        .to_string()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    /// Cubes shown in each draw, in the order they were drawn.
+    pub rounds: Vec<Cubes>,
+}
+
+/// A round that shows more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// 0-based index into [`Game::rounds`].
+    pub round: usize,
+    pub colour: String,
+    pub shown: u32,
+    pub available: u32,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "round {} shows {} {} but the bag holds {}",
+            self.round + 1,
+            self.shown,
+            self.colour,
+            self.available
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundStats {
+    pub total: u32,
+    pub colours: usize,
+    /// Most shown colour, the alphabetically first one on ties.
+    pub most: Option<(String, u32)>,
+}
+
+impl Game {
+    /// First round that could not have been drawn from `bag`. Colours missing
+    /// from the bag have no cubes.
+    pub fn violation(&self, bag: &Cubes) -> Option<Violation> {
+        self.rounds.iter().enumerate().find_map(|(round, cubes)| {
+            cubes.iter().find_map(|(colour, &shown)| {
+                let available = bag.get(colour).copied().unwrap_or(0);
+                (shown > available).then(|| Violation {
+                    round,
+                    colour: colour.clone(),
+                    shown,
+                    available,
+                })
+            })
+        })
+    }
+
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.violation(bag).is_none()
+    }
+
+    /// Fewest cubes of each colour that make all rounds possible.
+    pub fn minimum_bag(&self) -> Cubes {
+        self.minimum_bags().pop().unwrap_or_default()
+    }
+
+    /// Fewest cubes of each colour that make the rounds up to and including
+    /// each round possible.
+    pub fn minimum_bags(&self) -> Vec<Cubes> {
+        let mut bag = Cubes::new();
+        self.rounds
+            .iter()
+            .map(|round| {
+                for (colour, &n) in round {
+                    let max = bag.entry(colour.clone()).or_insert(0);
+                    *max = (*max).max(n);
+                }
+                bag.clone()
+            })
+            .collect()
+    }
+
+    pub fn round_stats(&self) -> Vec<RoundStats> {
+        self.rounds
+            .iter()
+            .map(|round| RoundStats {
+                total: round.values().sum(),
+                colours: round.len(),
+                most: round
+                    .iter()
+                    .rev()
+                    .max_by_key(|(_, &n)| n)
+                    .map(|(c, &n)| (c.clone(), n)),
+            })
+            .collect()
+    }
+}
+
+/// Parses a `Game N: ...` line.
+pub fn parse_game(input: &str) -> IResult<&str, Game> {
+    map(
+        separated_pair(
+            map(separated_pair(tag("Game"), space1, u32), |(_, id)| id),
+            tag(": "),
+            separated_list0(tag("; "), parse_draw),
+        ),
+        |(id, rounds)| Game { id, rounds },
+    )(input)
+}
+
+fn parse_draw(input: &str) -> IResult<&str, Cubes> {
+    map(
+        separated_list0(tag(", "), separated_pair(u32, space1, alpha1)),
+        |counts: Vec<(u32, &str)>| {
+            let mut draw = Cubes::new();
+            for (n, colour) in counts {
+                *draw.entry(colour.to_string()).or_insert(0) += n;
+            }
+            draw
+        },
+    )(input)
+}
+
+/// Ids of the games satisfying `constraint`.
+pub fn possible_games(input: &str, constraint: &Constraint) -> Vec<u32> {
+    input
+        .lines()
+        .map(|line| parse_game(line).unwrap().1)
+        .filter(|game| constraint.matches(game))
+        .map(|game| game.id)
+        .collect()
+}
+
+/// Hypothesis about the bag, e.g. `red<=12 && green<=13 || total<=20`.
+///
+/// Comparisons are checked against the game's minimum bag: a colour name stands
+/// for the fewest cubes of that colour the bag must hold and `total` for the
+/// sum over all colours. `!` binds tighter than `&&`, which binds tighter than
+/// `||`, and parentheses group.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    Compare(Quantity, Op, u32),
+    Not(Box<Constraint>),
+    And(Box<Constraint>, Box<Constraint>),
+    Or(Box<Constraint>, Box<Constraint>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Quantity {
+    Colour(String),
+    Total,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    fn apply(self, left: u32, right: u32) -> bool {
+        match self {
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right,
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+        }
+    }
+}
+
+impl Constraint {
+    pub fn matches(&self, game: &Game) -> bool {
+        self.matches_bag(&game.minimum_bag())
+    }
+
+    fn matches_bag(&self, bag: &Cubes) -> bool {
+        match self {
+            Constraint::Compare(quantity, op, n) => {
+                let amount = match quantity {
+                    Quantity::Colour(colour) => bag.get(colour).copied().unwrap_or(0),
+                    Quantity::Total => bag.values().sum(),
+                };
+                op.apply(amount, *n)
+            }
+            Constraint::Not(c) => !c.matches_bag(bag),
+            Constraint::And(a, b) => a.matches_bag(bag) && b.matches_bag(bag),
+            Constraint::Or(a, b) => a.matches_bag(bag) || b.matches_bag(bag),
+        }
+    }
+}
+
+/// Parses a whole constraint expression, surrounding whitespace allowed.
+pub fn parse_constraint(input: &str) -> IResult<&str, Constraint> {
+    all_consuming(delimited(multispace0, parse_or, multispace0))(input)
+}
+
+fn parse_or(input: &str) -> IResult<&str, Constraint> {
+    map(separated_list1(token("||"), parse_and), |terms| {
+        fold(terms, Constraint::Or)
+    })(input)
+}
+
+fn parse_and(input: &str) -> IResult<&str, Constraint> {
+    map(separated_list1(token("&&"), parse_unary), |terms| {
+        fold(terms, Constraint::And)
+    })(input)
+}
+
+fn parse_unary(input: &str) -> IResult<&str, Constraint> {
+    alt((
+        map(preceded(token("!"), parse_unary), |c| {
+            Constraint::Not(Box::new(c))
+        }),
+        delimited(token("("), parse_or, token(")")),
+        map(
+            tuple((
+                delimited(multispace0, alpha1, multispace0),
+                parse_op,
+                delimited(multispace0, u32, multispace0),
+            )),
+            |(name, op, n)| {
+                let quantity = match name {
+                    "total" => Quantity::Total,
+                    colour => Quantity::Colour(colour.to_string()),
+                };
+                Constraint::Compare(quantity, op, n)
+            },
+        ),
+    ))(input)
+}
+
+fn parse_op(input: &str) -> IResult<&str, Op> {
+    alt((
+        value(Op::Le, tag("<=")),
+        value(Op::Ge, tag(">=")),
+        value(Op::Eq, tag("==")),
+        value(Op::Ne, tag("!=")),
+        value(Op::Lt, char('<')),
+        value(Op::Gt, char('>')),
+    ))(input)
+}
+
+fn token<'a>(t: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    delimited(multispace0, tag(t), multispace0)
+}
+
+fn fold(
+    terms: Vec<Constraint>,
+    join: fn(Box<Constraint>, Box<Constraint>) -> Constraint,
+) -> Constraint {
+    terms
+        .into_iter()
+        .reduce(|a, b| join(Box::new(a), Box::new(b)))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use super::{Cubes, Game, RoundStats, Violation};
+
+    fn cubes(counts: &[(&str, u32)]) -> Cubes {
+        counts.iter().map(|&(c, n)| (c.to_string(), n)).collect()
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 15: 1 blue, 2 red; 3 red, 4 green, 5 blue; 6 green";
+
+        assert_eq!(
+            super::parse_game(input),
+            Ok((
+                "",
+                Game {
+                    id: 15,
+                    rounds: vec![
+                        cubes(&[("blue", 1), ("red", 2)]),
+                        cubes(&[("red", 3), ("green", 4), ("blue", 5)]),
+                        cubes(&[("green", 6)]),
+                    ]
+                }
+            ))
+        )
+    }
+
+    #[test]
+    fn test_other_colours() {
+        let input = "Game 1: 2 cyan, 1 magenta; 3 cyan\nGame 2: 4 magenta, 1 cyan; 2 cyan";
+
+        let output = super::part2(input);
+
+        assert_eq!(output, "11");
+        assert!(super::parse_game("Game 3: 3 cyan; 1 magenta")
+            .unwrap()
+            .1
+            .is_possible(&cubes(&[("cyan", 3), ("magenta", 1)])));
+    }
+
+    #[test]
+    fn test_rounds() {
+        let game = super::parse_game(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap()
+        .1;
+        let bag = cubes(&[("red", 12), ("green", 13), ("blue", 14)]);
+
+        let violation = game.violation(&bag).unwrap();
+
+        assert_eq!(
+            violation,
+            Violation {
+                round: 0,
+                colour: "red".to_string(),
+                shown: 20,
+                available: 12
+            }
+        );
+        assert_eq!(
+            violation.to_string(),
+            "round 1 shows 20 red but the bag holds 12"
+        );
+        assert_eq!(
+            game.minimum_bags()[1],
+            cubes(&[("red", 20), ("green", 13), ("blue", 6)])
+        );
+        assert_eq!(
+            game.round_stats()[2],
+            RoundStats {
+                total: 6,
+                colours: 2,
+                most: Some(("green".to_string(), 5))
+            }
+        );
+    }
+
+    #[test]
+    fn test_constraints() {
+        let input = example!(day02, part1);
+        let query = |q| super::possible_games(input, &super::parse_constraint(q).unwrap().1);
+
+        assert_eq!(query("red<=12 && green<=13 && blue<=14"), vec![1, 2, 5]);
+        assert_eq!(
+            query("red <= 12 && green <= 13 || total <= 35"),
+            vec![1, 2, 4, 5]
+        );
+        assert_eq!(query("total > 30"), vec![3, 4]);
+        assert_eq!(query("!(red < 5 || blue == 6) && green != 2"), vec![4, 5]);
+        assert!(super::parse_constraint("red <= 12 &&").is_err());
+        assert!(super::parse_constraint("red = 12").is_err());
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day02, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day02, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day02, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day02, part2))
+    }
+}
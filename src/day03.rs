@@ -0,0 +1,471 @@
+pub fn part1(input: &str) -> String {
+    let scheme = Scheme::parse(input);
+
+    scheme
+        .part_numbers()
+        .map(u64::from)
+        .sum::<u64>()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let scheme = Scheme::parse(input);
+
+    scheme.gears().sum::<u128>().to_string()
+}
+
+/// Engine schematic: the numbers and symbols found on the grid.
+pub struct Scheme {
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// What occupies each cell, indexed by line and column.
+    cells: Vec<Vec<Cell>>,
+}
+
+/// Number spanning `len` cells from column `index` of `line`.
+pub struct Number {
+    pub line: usize,
+    pub index: usize,
+    pub len: usize,
+    pub value: u32,
+}
+
+/// Any non-digit, non-`.` cell.
+pub struct Symbol {
+    pub line: usize,
+    pub index: usize,
+    pub value: char,
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Empty,
+    Number(usize),
+    Symbol(usize),
+}
+
+impl Scheme {
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    pub fn parse(input: &str) -> Self {
+        let mut numbers = Vec::new();
+        let mut symbols = Vec::new();
+        let mut cells = Vec::new();
+
+        for (j, line) in input.lines().enumerate() {
+            let mut row = vec![Cell::Empty; line.len()];
+            let mut left = None;
+            for (i, ch) in line.char_indices() {
+                if ch.is_ascii_digit() {
+                    if left.is_none() {
+                        left = Some(i)
+                    }
+                    row[i] = Cell::Number(numbers.len());
+                } else {
+                    if let Some(idx) = left {
+                        numbers.push(Number {
+                            line: j,
+                            index: idx,
+                            len: i - idx,
+                            value: line[idx..i].parse().unwrap(),
+                        });
+                        left = None
+                    }
+                    if ch != '.' {
+                        row[i] = Cell::Symbol(symbols.len());
+                        symbols.push(Symbol {
+                            line: j,
+                            index: i,
+                            value: ch,
+                        })
+                    }
+                }
+            }
+            if let Some(idx) = left {
+                numbers.push(Number {
+                    line: j,
+                    index: idx,
+                    len: line.len() - idx,
+                    value: line[idx..line.len()].parse().unwrap(),
+                });
+            }
+            cells.push(row);
+        }
+        Self {
+            numbers,
+            symbols,
+            cells,
+        }
+    }
+
+    /// Indices into `numbers` of the numbers touching `symbol`, each once.
+    pub fn neighbours(&self, symbol: &Symbol) -> Vec<usize> {
+        let mut found = Vec::new();
+        for line in symbol.line.saturating_sub(1)..=symbol.line + 1 {
+            for index in symbol.index.saturating_sub(1)..=symbol.index + 1 {
+                if let Some(Cell::Number(n)) = self.cell(line, index) {
+                    // Digits of one number are contiguous within a line.
+                    if found.last() != Some(&n) {
+                        found.push(n);
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Indices into `symbols` of the symbols touching `number`.
+    pub fn symbols_around(&self, number: &Number) -> Vec<usize> {
+        let mut found = Vec::new();
+        for line in number.line.saturating_sub(1)..=number.line + 1 {
+            for index in number.index.saturating_sub(1)..=number.index + number.len {
+                if let Some(Cell::Symbol(s)) = self.cell(line, index) {
+                    found.push(s);
+                }
+            }
+        }
+        found
+    }
+
+    fn cell(&self, line: usize, index: usize) -> Option<Cell> {
+        self.cells.get(line)?.get(index).copied()
+    }
+
+    /// Values of the numbers adjacent to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut is_part = vec![false; self.numbers.len()];
+        for s in &self.symbols {
+            for n in self.neighbours(s) {
+                is_part[n] = true;
+            }
+        }
+        self.numbers
+            .iter()
+            .zip(is_part)
+            .filter(|(_, is_part)| *is_part)
+            .map(|(n, _)| n.value)
+    }
+
+    /// Gear ratios of `*` symbols adjacent to exactly two numbers.
+    pub fn gears(&self) -> impl Iterator<Item = u128> {
+        let gear = Rule::new('*', Aggregation::Product).with_arity(Arity::Exactly(2));
+        self.evaluate(&[gear]).pop().unwrap().into_iter()
+    }
+
+    /// Adjacency edges as `(number, symbol)` index pairs.
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        self.symbols
+            .iter()
+            .enumerate()
+            .flat_map(|(s, symbol)| self.neighbours(symbol).into_iter().map(move |n| (n, s)))
+            .collect()
+    }
+
+    /// Numbers touching no symbol.
+    pub fn orphan_numbers(&self) -> Vec<usize> {
+        (0..self.numbers.len())
+            .filter(|&n| self.symbols_around(&self.numbers[n]).is_empty())
+            .collect()
+    }
+
+    /// Symbols touching no number.
+    pub fn lonely_symbols(&self) -> Vec<usize> {
+        (0..self.symbols.len())
+            .filter(|&s| self.neighbours(&self.symbols[s]).is_empty())
+            .collect()
+    }
+
+    /// Bipartite number/symbol graph in Graphviz format. Orphan numbers and
+    /// lonely symbols are drawn dashed.
+    pub fn to_dot(&self) -> String {
+        let orphans = self.orphan_numbers();
+        let lonely = self.lonely_symbols();
+        let style = |isolated: bool| if isolated { " style=dashed" } else { "" };
+
+        let mut out = String::from("graph scheme {\n");
+        for (i, n) in self.numbers.iter().enumerate() {
+            out.push_str(&format!(
+                "    n{} [label=\"{}\" shape=box{}];\n",
+                i,
+                n.value,
+                style(orphans.contains(&i))
+            ));
+        }
+        for (i, s) in self.symbols.iter().enumerate() {
+            out.push_str(&format!(
+                "    s{} [label=\"{}\" shape=diamond{}];\n",
+                i,
+                escape(s.value),
+                style(lonely.contains(&i))
+            ));
+        }
+        for (n, s) in self.edges() {
+            out.push_str(&format!("    n{} -- s{};\n", n, s));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Nodes with their positions, edges, orphan numbers and lonely symbols as JSON.
+    pub fn to_json(&self) -> String {
+        let numbers = self
+            .numbers
+            .iter()
+            .map(|n| {
+                format!(
+                    "{{\"value\": {}, \"line\": {}, \"index\": {}, \"len\": {}}}",
+                    n.value, n.line, n.index, n.len
+                )
+            })
+            .collect::<Vec<_>>();
+        let symbols = self
+            .symbols
+            .iter()
+            .map(|s| {
+                format!(
+                    "{{\"value\": \"{}\", \"line\": {}, \"index\": {}}}",
+                    escape(s.value),
+                    s.line,
+                    s.index
+                )
+            })
+            .collect::<Vec<_>>();
+        let edges = self
+            .edges()
+            .iter()
+            .map(|(n, s)| format!("{{\"number\": {}, \"symbol\": {}}}", n, s))
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\n  \"numbers\": {},\n  \"symbols\": {},\n  \"edges\": {},\n  \"orphan_numbers\": {:?},\n  \"lonely_symbols\": {:?}\n}}\n",
+            json_array(&numbers),
+            json_array(&symbols),
+            json_array(&edges),
+            self.orphan_numbers(),
+            self.lonely_symbols()
+        )
+    }
+
+    /// Applies all `rules` in a single pass over the symbols. Returns, for each
+    /// rule, the aggregated neighbours of every symbol the rule matched.
+    pub fn evaluate(&self, rules: &[Rule]) -> Vec<Vec<u128>> {
+        let mut results = vec![Vec::new(); rules.len()];
+        for symbol in &self.symbols {
+            let mut values = None;
+            for (rule, result) in rules.iter().zip(results.iter_mut()) {
+                if rule.symbol != symbol.value {
+                    continue;
+                }
+                let values = values.get_or_insert_with(|| {
+                    self.neighbours(symbol)
+                        .into_iter()
+                        .map(|n| self.numbers[n].value)
+                        .collect::<Vec<_>>()
+                });
+                if let Some(value) = rule.apply(values) {
+                    result.push(value);
+                }
+            }
+        }
+        results
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aggregation {
+    Product,
+    Sum,
+    Count,
+    Min,
+    Max,
+}
+
+/// How many numbers a symbol must touch for a rule to apply.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arity {
+    Any,
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Arity {
+    fn allows(self, n: usize) -> bool {
+        match self {
+            Arity::Any => true,
+            Arity::Exactly(k) => n == k,
+            Arity::AtLeast(k) => n >= k,
+            Arity::AtMost(k) => n <= k,
+        }
+    }
+}
+
+/// Aggregates the numbers around every `symbol` whose neighbour count fits `arity`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rule {
+    pub symbol: char,
+    pub arity: Arity,
+    pub aggregation: Aggregation,
+}
+
+impl Rule {
+    pub fn new(symbol: char, aggregation: Aggregation) -> Self {
+        Self {
+            symbol,
+            arity: Arity::Any,
+            aggregation,
+        }
+    }
+
+    pub fn with_arity(mut self, arity: Arity) -> Self {
+        self.arity = arity;
+        self
+    }
+
+    /// `None` if the arity does not fit, or for a product, min or max of no numbers.
+    /// A symbol touches at most six numbers, so only a product of more than
+    /// four can exceed `u128`; it saturates.
+    fn apply(&self, values: &[u32]) -> Option<u128> {
+        if !self.arity.allows(values.len()) {
+            return None;
+        }
+        let values = values.iter().map(|&v| v as u128);
+        match self.aggregation {
+            Aggregation::Product => values.reduce(|a, b| a.saturating_mul(b)),
+            Aggregation::Sum => Some(values.sum()),
+            Aggregation::Count => Some(values.len() as u128),
+            Aggregation::Min => values.min(),
+            Aggregation::Max => values.max(),
+        }
+    }
+}
+
+/// Escapes a symbol for a double-quoted DOT or JSON string.
+fn escape(c: char) -> String {
+    match c {
+        '"' | '\\' => format!("\\{}", c),
+        c => c.to_string(),
+    }
+}
+
+fn json_array(items: &[String]) -> String {
+    if items.is_empty() {
+        return "[]".to_string();
+    }
+    format!("[\n    {}\n  ]", items.join(",\n    "))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use super::{Aggregation, Arity, Rule};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day03, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day03, part1))
+    }
+
+    #[test]
+    fn test_part_numbers_counted_once() {
+        let scheme = super::Scheme::parse("*12#\n....\n.7.5\n..+.");
+
+        assert_eq!(scheme.part_numbers().collect::<Vec<_>>(), vec![12, 7, 5]);
+        assert_eq!(scheme.neighbours(&scheme.symbols[2]), vec![1, 2]);
+        assert_eq!(scheme.symbols_around(&scheme.numbers[0]), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_rules() {
+        let scheme = super::Scheme::parse(example!(day03, part1));
+        let rules = [
+            Rule::new('#', Aggregation::Sum),
+            Rule::new('*', Aggregation::Count),
+            Rule::new('*', Aggregation::Max).with_arity(Arity::AtMost(1)),
+            Rule::new('+', Aggregation::Min).with_arity(Arity::AtLeast(3)),
+        ];
+
+        let results = scheme.evaluate(&rules);
+
+        assert_eq!(results, vec![vec![633], vec![2, 1, 2], vec![617], vec![]]);
+    }
+
+    #[test]
+    fn test_rules_do_not_overflow() {
+        let scheme = super::Scheme::parse("999.999\n...*...\n999.999");
+        let rules = [
+            Rule::new('*', Aggregation::Product).with_arity(Arity::AtLeast(3)),
+            Rule::new('*', Aggregation::Sum),
+        ];
+
+        let results = scheme.evaluate(&rules);
+
+        assert_eq!(results, vec![vec![999u128.pow(4)], vec![4 * 999]]);
+
+        let wide = "4294967295.4294967295\n4294967295*4294967295\n4294967295.4294967295";
+        let results = super::Scheme::parse(wide).evaluate(&rules);
+
+        assert_eq!(results, vec![vec![u128::MAX], vec![6 * u32::MAX as u128]]);
+        assert_eq!(super::part1(wide), (6 * u32::MAX as u64).to_string());
+    }
+
+    #[test]
+    fn test_graph() {
+        let scheme = super::Scheme::parse("12.\\\n..*\n\"...7");
+
+        assert_eq!(scheme.edges(), vec![(0, 1)]);
+        assert_eq!(scheme.orphan_numbers(), vec![1]);
+        assert_eq!(scheme.lonely_symbols(), vec![0, 2]);
+        assert_eq!(
+            scheme.to_dot(),
+            r#"graph scheme {
+    n0 [label="12" shape=box];
+    n1 [label="7" shape=box style=dashed];
+    s0 [label="\\" shape=diamond style=dashed];
+    s1 [label="*" shape=diamond];
+    s2 [label="\"" shape=diamond style=dashed];
+    n0 -- s1;
+}
+"#
+        );
+        assert_eq!(
+            scheme.to_json(),
+            r#"{
+  "numbers": [
+    {"value": 12, "line": 0, "index": 0, "len": 2},
+    {"value": 7, "line": 2, "index": 4, "len": 1}
+  ],
+  "symbols": [
+    {"value": "\\", "line": 0, "index": 3},
+    {"value": "*", "line": 1, "index": 2},
+    {"value": "\"", "line": 2, "index": 0}
+  ],
+  "edges": [
+    {"number": 0, "symbol": 1}
+  ],
+  "orphan_numbers": [1],
+  "lonely_symbols": [0, 2]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day03, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day03, part2))
+    }
+}
@@ -0,0 +1,611 @@
+use std::cmp::min;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+
+use nom::character::complete::{space1, u32};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, separated_pair, tuple};
+use nom::{bytes::complete::tag, IResult};
+
+pub fn part1(input: &str) -> String {
+    let cards = input
+        .lines()
+        .map(|line| parse_card(line).unwrap().1)
+        .collect::<Vec<_>>();
+
+    Engine::standard()
+        .points(&cards)
+        .expect("total points do not fit in 64 bits")
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let cards = input
+        .lines()
+        .map(|line| parse_card(line).unwrap().1)
+        .collect::<Vec<_>>();
+
+    Engine::standard()
+        .copies(&cards)
+        .unwrap()
+        .iter()
+        .sum::<usize>()
+        .to_string()
+}
+
+/// Points a card is worth for its number of matches, `None` if they do not
+/// fit in 64 bits.
+pub trait Scoring {
+    fn score(&self, matches: usize) -> Option<u64>;
+}
+
+/// 1 point for the first match, doubled for every further one.
+pub struct Doubling;
+
+/// 1 point per match.
+pub struct Linear;
+
+/// Fibonacci sequence: 1, 1, 2, 3, 5, ... points.
+pub struct Fibonacci;
+
+impl Scoring for Doubling {
+    fn score(&self, matches: usize) -> Option<u64> {
+        match matches {
+            0 => Some(0),
+            n => 1u64.checked_shl(u32::try_from(n - 1).ok()?),
+        }
+    }
+}
+
+impl Scoring for Linear {
+    fn score(&self, matches: usize) -> Option<u64> {
+        u64::try_from(matches).ok()
+    }
+}
+
+impl Scoring for Fibonacci {
+    fn score(&self, matches: usize) -> Option<u64> {
+        if matches == 0 {
+            return Some(0);
+        }
+        (1..matches)
+            .try_fold((0u64, 1u64), |(a, b), _| Some((b, a.checked_add(b)?)))
+            .map(|(_, b)| b)
+    }
+}
+
+/// Cards that receive a copy when the card at `index` of `len` cards wins.
+pub trait Propagation {
+    fn targets(&self, index: usize, matches: usize, len: usize) -> Range<usize>;
+
+    /// Whether copies only go to earlier cards, so that cards settle from the
+    /// last one to the first rather than the other way round.
+    fn flows_backward(&self) -> bool {
+        false
+    }
+}
+
+/// One copy of each of the next `matches` cards.
+pub struct NextCards;
+
+/// One copy of each of the previous `matches` cards.
+pub struct PreviousCards;
+
+/// Like [`NextCards`], but never more than the given number of cards.
+pub struct Window(pub usize);
+
+impl Propagation for NextCards {
+    fn targets(&self, index: usize, matches: usize, len: usize) -> Range<usize> {
+        min(len, index + 1)..min(len, index + 1 + matches)
+    }
+}
+
+impl Propagation for PreviousCards {
+    fn targets(&self, index: usize, matches: usize, _len: usize) -> Range<usize> {
+        index.saturating_sub(matches)..index
+    }
+
+    fn flows_backward(&self) -> bool {
+        true
+    }
+}
+
+impl Propagation for Window {
+    fn targets(&self, index: usize, matches: usize, len: usize) -> Range<usize> {
+        NextCards.targets(index, min(matches, self.0), len)
+    }
+}
+
+/// Copies were still being won after as many rounds as there are cards, so
+/// the propagation rule sends copies around in a cycle.
+#[derive(Debug, PartialEq)]
+pub struct NeverSettles;
+
+impl fmt::Display for NeverSettles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "copies are won in a cycle and never settle")
+    }
+}
+
+impl std::error::Error for NeverSettles {}
+
+#[derive(Debug, PartialEq)]
+pub struct CardStats {
+    pub id: usize,
+    pub matches: usize,
+    /// `None` if the points do not fit in 64 bits; copies are counted anyway.
+    pub points: Option<u64>,
+    /// Instances held in the end, the original included.
+    pub copies: usize,
+    /// Cards that won copies of this one, as `(id, copies won)` ordered by id.
+    pub sources: Vec<(usize, usize)>,
+}
+
+/// Instances of `card` that appeared in `round` of the cascade and the copies
+/// they won for the next round. Round 0 holds the original cards.
+#[derive(Debug, PartialEq)]
+pub struct Event {
+    pub round: usize,
+    pub card: usize,
+    pub instances: usize,
+    pub won: usize,
+}
+
+pub struct Evaluation {
+    pub stats: Vec<CardStats>,
+    pub timeline: Vec<Event>,
+}
+
+/// Evaluates a scoring and a copy-propagation rule over a list of cards.
+pub struct Engine {
+    scoring: Box<dyn Scoring>,
+    propagation: Box<dyn Propagation>,
+}
+
+impl Engine {
+    pub fn new(scoring: impl Scoring + 'static, propagation: impl Propagation + 'static) -> Self {
+        Self {
+            scoring: Box::new(scoring),
+            propagation: Box::new(propagation),
+        }
+    }
+
+    /// The puzzle rules: [`Doubling`] and [`NextCards`].
+    pub fn standard() -> Self {
+        Self::new(Doubling, NextCards)
+    }
+
+    /// Parses `<scoring>,<propagation>` where scoring is `doubling`, `linear`
+    /// or `fibonacci` and propagation is `next`, `previous` or `window:<n>`.
+    pub fn parse(rules: &str) -> Option<Self> {
+        let (scoring, propagation) = rules.split_once(',')?;
+        let scoring: Box<dyn Scoring> = match scoring {
+            "doubling" => Box::new(Doubling),
+            "linear" => Box::new(Linear),
+            "fibonacci" => Box::new(Fibonacci),
+            _ => return None,
+        };
+        let propagation: Box<dyn Propagation> = match propagation.split_once(':') {
+            None if propagation == "next" => Box::new(NextCards),
+            None if propagation == "previous" => Box::new(PreviousCards),
+            Some(("window", n)) => Box::new(Window(n.parse().ok()?)),
+            _ => return None,
+        };
+        Some(Self {
+            scoring,
+            propagation,
+        })
+    }
+
+    /// Total points of `cards`, `None` if they do not fit in 64 bits.
+    pub fn points(&self, cards: &[Card]) -> Option<u64> {
+        cards.iter().try_fold(0u64, |total, card| {
+            total.checked_add(self.scoring.score(card.match_count())?)
+        })
+    }
+
+    /// Instances of every card held in the end, the original included.
+    ///
+    /// Settles the cards in a single sweep in the direction copies flow, and
+    /// only replays round by round if the propagation sends copies against it.
+    pub fn copies(&self, cards: &[Card]) -> Result<Vec<usize>, NeverSettles> {
+        let len = cards.len();
+        let matches = cards.iter().map(Card::match_count).collect::<Vec<_>>();
+        let order: Box<dyn Iterator<Item = usize>> = if self.propagation.flows_backward() {
+            Box::new((0..len).rev())
+        } else {
+            Box::new(0..len)
+        };
+
+        let mut copies = vec![1; len];
+        let mut settled = vec![false; len];
+        for i in order {
+            settled[i] = true;
+            for t in self.propagation.targets(i, matches[i], len) {
+                if settled[t] {
+                    return self.copies_by_rounds(&matches);
+                }
+                copies[t] += copies[i];
+            }
+        }
+        Ok(copies)
+    }
+
+    fn copies_by_rounds(&self, matches: &[usize]) -> Result<Vec<usize>, NeverSettles> {
+        let len = matches.len();
+        let mut copies = vec![0; len];
+        let mut current = vec![1usize; len];
+        for round in 0.. {
+            if current.iter().all(|&n| n == 0) {
+                break;
+            }
+            if round > len {
+                return Err(NeverSettles);
+            }
+            let mut next = vec![0; len];
+            for (i, &instances) in current.iter().enumerate() {
+                copies[i] += instances;
+                for t in self.propagation.targets(i, matches[i], len) {
+                    next[t] += instances;
+                }
+            }
+            current = next;
+        }
+        Ok(copies)
+    }
+
+    /// Replays the win propagation round by round until no new copies are won,
+    /// recording where every copy came from. Prefer [`Engine::copies`] when
+    /// only the counts are needed.
+    /// `cards` must be ordered by id.
+    pub fn run(&self, cards: &[Card]) -> Result<Evaluation, NeverSettles> {
+        let mut stats = cards
+            .iter()
+            .map(|card| CardStats {
+                id: card.id,
+                matches: card.match_count(),
+                points: self.scoring.score(card.match_count()),
+                copies: 0,
+                sources: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut sources = vec![BTreeMap::new(); cards.len()];
+        let mut timeline = Vec::new();
+
+        let mut current = vec![1usize; cards.len()];
+        for round in 0.. {
+            if current.iter().all(|&n| n == 0) {
+                break;
+            }
+            if round > cards.len() {
+                return Err(NeverSettles);
+            }
+            let mut next = vec![0; cards.len()];
+            for (i, &instances) in current.iter().enumerate().filter(|(_, &n)| n > 0) {
+                stats[i].copies += instances;
+                let targets = self.propagation.targets(i, stats[i].matches, cards.len());
+                for t in targets.clone() {
+                    next[t] += instances;
+                    *sources[t].entry(cards[i].id).or_insert(0) += instances;
+                }
+                timeline.push(Event {
+                    round,
+                    card: cards[i].id,
+                    instances,
+                    won: instances * targets.len(),
+                });
+            }
+            current = next;
+        }
+
+        for (stats, sources) in stats.iter_mut().zip(sources) {
+            stats.sources = sources.into_iter().collect();
+        }
+        Ok(Evaluation { stats, timeline })
+    }
+}
+
+pub fn timeline_csv(events: &[Event]) -> String {
+    let mut csv = String::from("round,card,instances,won\n");
+    for e in events {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            e.round, e.card, e.instances, e.won
+        ));
+    }
+    csv
+}
+
+/// Scratchcard with its winning numbers and the numbers you have.
+#[derive(Debug, Clone)]
+pub struct Card {
+    pub id: usize,
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+    winning: NumberSet,
+    own: NumberSet,
+    /// Your numbers already seen earlier in the list, each of which matches again.
+    repeated: Vec<u32>,
+}
+
+impl Card {
+    pub fn new(id: usize, winning_numbers: Vec<u32>, numbers: Vec<u32>) -> Self {
+        let (own, repeated) = NumberSet::with_repeats(&numbers);
+        Self {
+            id,
+            winning: NumberSet::new(&winning_numbers),
+            own,
+            repeated,
+            winning_numbers,
+            numbers,
+        }
+    }
+
+    pub fn winning_numbers(&self) -> &[u32] {
+        &self.winning_numbers
+    }
+
+    /// The numbers you have.
+    pub fn numbers(&self) -> &[u32] {
+        &self.numbers
+    }
+
+    /// Number of your numbers that are winning numbers, a repeated number
+    /// counting every time.
+    pub fn match_count(&self) -> usize {
+        self.own.intersection_len(&self.winning)
+            + self
+                .repeated
+                .iter()
+                .filter(|&&n| self.winning.contains(n))
+                .count()
+    }
+}
+
+/// Values at or above this do not fit a [`NumberSet::Bits`].
+pub const BITSET_WIDTH: u32 = 256;
+
+/// Set of card numbers: a fixed-width bitset when every value is below
+/// [`BITSET_WIDTH`], a hash set otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumberSet {
+    Bits([u64; (BITSET_WIDTH / 64) as usize]),
+    Hashed(HashSet<u32>),
+}
+
+impl NumberSet {
+    pub fn new(values: &[u32]) -> Self {
+        Self::with_repeats(values).0
+    }
+
+    /// The set of `values` and, in order, the values already seen before.
+    pub fn with_repeats(values: &[u32]) -> (Self, Vec<u32>) {
+        let mut repeated = Vec::new();
+        if values.iter().any(|&n| n >= BITSET_WIDTH) {
+            let mut set = HashSet::with_capacity(values.len());
+            for &n in values {
+                if !set.insert(n) {
+                    repeated.push(n);
+                }
+            }
+            return (NumberSet::Hashed(set), repeated);
+        }
+        let mut bits = [0u64; (BITSET_WIDTH / 64) as usize];
+        for &n in values {
+            let (word, bit) = (n as usize / 64, 1 << (n % 64));
+            if bits[word] & bit != 0 {
+                repeated.push(n);
+            }
+            bits[word] |= bit;
+        }
+        (NumberSet::Bits(bits), repeated)
+    }
+
+    pub fn contains(&self, n: u32) -> bool {
+        match self {
+            NumberSet::Bits(bits) => {
+                n < BITSET_WIDTH && bits[n as usize / 64] & (1 << (n % 64)) != 0
+            }
+            NumberSet::Hashed(set) => set.contains(&n),
+        }
+    }
+
+    pub fn intersection_len(&self, other: &NumberSet) -> usize {
+        match (self, other) {
+            (NumberSet::Bits(a), NumberSet::Bits(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(a, b)| (a & b).count_ones() as usize)
+                .sum(),
+            (NumberSet::Hashed(a), NumberSet::Hashed(b)) if a.len() > b.len() => {
+                b.iter().filter(|n| a.contains(n)).count()
+            }
+            (NumberSet::Hashed(set), other) | (other, NumberSet::Hashed(set)) => {
+                set.iter().filter(|&&n| other.contains(n)).count()
+            }
+        }
+    }
+}
+
+pub fn parse_card(input: &str) -> IResult<&str, Card> {
+    map(
+        separated_pair(
+            separated_pair(tag("Card"), space1, u32),
+            tuple((tag(":"), space1)),
+            separated_pair(
+                separated_list1(space1, u32),
+                delimited(space1, tag("|"), space1),
+                separated_list1(space1, u32),
+            ),
+        ),
+        |((_, id), (winning_numbers, numbers))| Card::new(id as usize, winning_numbers, numbers),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use proptest::prelude::*;
+
+    use std::ops::Range;
+
+    use super::{
+        Card, CardStats, Doubling, Engine, Evaluation, Fibonacci, Linear, NeverSettles,
+        Propagation, Scoring,
+    };
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day04, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day04, part1))
+    }
+
+    #[test]
+    fn test_card_stats() {
+        let cards = example!(day04, part1)
+            .lines()
+            .map(|line| super::parse_card(line).unwrap().1)
+            .collect::<Vec<_>>();
+
+        let Evaluation { stats, timeline } = Engine::standard().run(&cards).unwrap();
+
+        assert_eq!(
+            stats[3],
+            CardStats {
+                id: 4,
+                matches: 1,
+                points: Some(1),
+                copies: 8,
+                sources: vec![(1, 1), (2, 2), (3, 4)],
+            }
+        );
+        assert_eq!(
+            super::timeline_csv(&timeline[..3]),
+            "round,card,instances,won\n0,1,1,4\n0,2,1,2\n0,3,1,2\n"
+        );
+    }
+
+    #[test]
+    fn test_rule_sets() {
+        let cards = example!(day04, part1)
+            .lines()
+            .map(|line| super::parse_card(line).unwrap().1)
+            .collect::<Vec<_>>();
+        let run = |rules| {
+            let engine = Engine::parse(rules).unwrap();
+            let stats = engine.run(&cards).unwrap().stats;
+            let copies = stats.iter().map(|s| s.copies).collect::<Vec<_>>();
+            assert_eq!(engine.copies(&cards), Ok(copies), "{}", rules);
+            stats
+        };
+        let points =
+            |stats: &[CardStats]| stats.iter().map(|s| s.points.unwrap()).collect::<Vec<_>>();
+        let copies = |stats: &[CardStats]| stats.iter().map(|s| s.copies).collect::<Vec<_>>();
+
+        assert_eq!(points(&run("linear,next")), vec![4, 2, 2, 1, 0, 0]);
+        assert_eq!(points(&run("fibonacci,next")), vec![3, 1, 1, 1, 0, 0]);
+        assert_eq!(copies(&run("doubling,window:1")), vec![1, 2, 3, 4, 5, 1]);
+        assert_eq!(copies(&run("doubling,previous")), vec![6, 3, 2, 1, 1, 1]);
+        assert!(Engine::parse("doubling,sideways").is_none());
+    }
+
+    #[test]
+    fn test_copies_do_not_depend_on_points() {
+        let numbers = (1..=70)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input = format!("Card 1: {0} | {0}\nCard 2: 1 | 2", numbers);
+
+        let output = super::part2(&input);
+
+        assert_eq!(output, "3");
+        let cards = input
+            .lines()
+            .map(|line| super::parse_card(line).unwrap().1)
+            .collect::<Vec<_>>();
+        let stats = Engine::standard().run(&cards).unwrap().stats;
+        assert_eq!(stats[0].points, None);
+        assert_eq!(stats[1].points, Some(0));
+    }
+
+    #[test]
+    fn test_repeated_numbers() {
+        let card = super::parse_card("Card 1: 5 5 | 5 5 7").unwrap().1;
+        let hashed = super::parse_card("Card 2: 5 300 | 5 300 300 5").unwrap().1;
+
+        assert_eq!(card.match_count(), 2);
+        assert_eq!(hashed.match_count(), 4);
+    }
+
+    #[test]
+    fn test_scoring_overflow() {
+        assert_eq!(Doubling.score(64), Some(1 << 63));
+        assert_eq!(Doubling.score(65), None);
+        assert_eq!(Fibonacci.score(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(Fibonacci.score(94), None);
+    }
+
+    #[test]
+    fn test_cycle_never_settles() {
+        struct Itself;
+
+        impl Propagation for Itself {
+            fn targets(&self, index: usize, _matches: usize, _len: usize) -> Range<usize> {
+                index..index + 1
+            }
+        }
+
+        let cards = vec![Card::new(1, vec![1], vec![2])];
+
+        let result = Engine::new(Linear, Itself).run(&cards);
+
+        assert!(matches!(result, Err(NeverSettles)));
+        assert_eq!(
+            Engine::new(Linear, Itself).copies(&cards),
+            Err(NeverSettles)
+        );
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let input = (1..=20_000)
+            .map(|id| format!("Card {}: 1 | 1 2", id))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let output = super::part2(&input);
+
+        // Card i ends up with i instances.
+        assert_eq!(output, (20_000 * 20_001 / 2).to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn test_match_count_agrees_with_scan(
+            winning in prop::collection::vec(prop_oneof![0u32..300, any::<u32>()], 0..30),
+            numbers in prop::collection::vec(prop_oneof![0u32..300, any::<u32>()], 0..60),
+        ) {
+            let expected = numbers.iter().filter(|n| winning.contains(n)).count();
+
+            let card = Card::new(1, winning, numbers);
+
+            prop_assert_eq!(card.match_count(), expected);
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day04, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day04, part2))
+    }
+}
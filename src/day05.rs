@@ -0,0 +1,768 @@
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+use nom::character::complete::{alpha1, line_ending, multispace0, space1, u128};
+use nom::combinator::{all_consuming, map, verify};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, separated_pair, terminated, tuple};
+use nom::{bytes::complete::tag, IResult};
+
+pub fn part1(input: &str) -> String {
+    let almanac = Almanac::parse(input).unwrap().1;
+    almanac.validate().unwrap();
+    let composed = almanac.composed();
+
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| composed.convert(seed))
+        .min()
+        .unwrap()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let almanac = Almanac::parse(input).unwrap().1;
+    almanac.validate().unwrap();
+    let composed = almanac.composed();
+
+    almanac
+        .seeds
+        .chunks(2)
+        .flat_map(|r| composed.convert_range(r[0], r[1]))
+        .map(|r| r.0)
+        .min()
+        .unwrap()
+        .to_string()
+}
+
+/// Why the maps of an almanac do not form a chain of categories.
+#[derive(Debug, PartialEq)]
+pub enum ChainError {
+    UnknownCategory(String),
+    /// More than one map converts from this category.
+    Ambiguous(String),
+    /// The chain comes back to this category.
+    Cycle(String),
+    /// No map converts from this category, so the target cannot be reached.
+    Gap(String),
+    /// Map, as `(source, target)`, not on the chain from seed to location.
+    Disconnected(String, String),
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::UnknownCategory(c) => write!(f, "unknown category '{}'", c),
+            ChainError::Ambiguous(c) => write!(f, "more than one map from '{}'", c),
+            ChainError::Cycle(c) => write!(f, "maps loop back to '{}'", c),
+            ChainError::Gap(c) => write!(f, "no map from '{}'", c),
+            ChainError::Disconnected(source, target) => write!(
+                f,
+                "{}-to-{} map is not on the seed-to-location chain",
+                source, target
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// Seeds and the chain of maps converting a seed into a location.
+pub struct Almanac {
+    pub seeds: Vec<u128>,
+    /// Private so that [`Almanac::composed`] cannot go stale.
+    maps: Vec<Map>,
+    composed: OnceLock<PiecewiseMap>,
+}
+
+impl Almanac {
+    /// Fails unless the whole input is consumed, so that a rejected converter
+    /// cannot silently cut a map short.
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            all_consuming(terminated(
+                separated_pair(
+                    tuple((tag("seeds: "), separated_list1(space1, u128))),
+                    pair(line_ending, line_ending),
+                    separated_list1(pair(line_ending, line_ending), Map::parse),
+                ),
+                multispace0,
+            )),
+            |((_, seeds), maps)| Self {
+                seeds,
+                maps,
+                composed: OnceLock::new(),
+            },
+        )(input)
+    }
+
+    /// Checks that the maps form a single chain from `seed` to `location`,
+    /// in any order.
+    pub fn validate(&self) -> Result<(), ChainError> {
+        let chain = self.path("seed", "location")?;
+        match self
+            .maps
+            .iter()
+            .find(|m| !chain.iter().any(|c| std::ptr::eq(*c, *m)))
+        {
+            Some(m) => Err(ChainError::Disconnected(m.source.clone(), m.target.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Maps leading from category `from` to category `to`, in order.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<&Map>, ChainError> {
+        let mut outgoing = HashMap::new();
+        for m in &self.maps {
+            if outgoing.insert(m.source.as_str(), m).is_some() {
+                return Err(ChainError::Ambiguous(m.source.clone()));
+            }
+        }
+        let known =
+            |c: &str| c == "seed" || self.maps.iter().any(|m| m.source == c || m.target == c);
+        if let Some(unknown) = [from, to].into_iter().find(|c| !known(c)) {
+            return Err(ChainError::UnknownCategory(unknown.to_string()));
+        }
+
+        let mut path = Vec::new();
+        let mut visited = HashSet::from([from]);
+        let mut category = from;
+        while category != to {
+            let m = outgoing
+                .get(category)
+                .ok_or_else(|| ChainError::Gap(category.to_string()))?;
+            if !visited.insert(m.target.as_str()) {
+                return Err(ChainError::Cycle(m.target.clone()));
+            }
+            path.push(*m);
+            category = &m.target;
+        }
+        Ok(path)
+    }
+
+    /// Converts `value` of category `from` into category `to`.
+    pub fn convert(&self, from: &str, to: &str, value: u128) -> Result<u128, ChainError> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(value, |value, m| m.convert(value)))
+    }
+
+    /// Location of `seed` after passing it through every map.
+    pub fn location(&self, seed: u128) -> u128 {
+        self.composed().convert(seed)
+    }
+
+    pub fn maps(&self) -> &[Map] {
+        &self.maps
+    }
+
+    /// All maps merged into a single map from seed to location, built on
+    /// first use. Panics if the maps do not chain, see [`Almanac::validate`].
+    pub fn composed(&self) -> &PiecewiseMap {
+        self.composed.get_or_init(|| {
+            self.chain()
+                .iter()
+                .fold(PiecewiseMap::identity(), |composed, m| {
+                    composed.then(&PiecewiseMap::from_map(m))
+                })
+        })
+    }
+
+    fn chain(&self) -> Vec<&Map> {
+        self.path("seed", "location")
+            .unwrap_or_else(|err| panic!("invalid almanac: {}", err))
+    }
+
+    /// Seeds, as sorted disjoint `(start, length)` ranges, whose location lies
+    /// in `start..start + length`.
+    pub fn seeds_for_location(&self, start: u128, length: u128) -> Ranges {
+        self.chain()
+            .iter()
+            .rev()
+            .fold(vec![(start, length)], |ranges, m| {
+                let piecewise = PiecewiseMap::from_map(m);
+                normalize(
+                    ranges
+                        .iter()
+                        .flat_map(|&(start, length)| piecewise.invert(start, length))
+                        .collect(),
+                )
+            })
+    }
+
+    /// Location ranges, as `(start, length)`, of the seeds in `seed..seed + length`.
+    pub fn locations(&self, seed: u128, length: u128) -> Ranges {
+        self.composed().convert_range(seed, length)
+    }
+}
+
+/// One `source-to-target map` block of the almanac.
+pub struct Map {
+    pub source: String,
+    pub target: String,
+    pub converters: Vec<RangeConverter>,
+}
+
+impl Map {
+    pub fn parse(block: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(
+                separated_pair(alpha1, tag("-to-"), pair(alpha1, tag(" map:"))),
+                line_ending,
+                separated_list1(line_ending, RangeConverter::parse),
+            ),
+            |((source, (target, _)), converters)| Self {
+                source: source.to_string(),
+                target: target.to_string(),
+                converters,
+            },
+        )(block)
+    }
+
+    pub fn convert(&self, seed: u128) -> u128 {
+        self.converters
+            .iter()
+            .find_map(|c| c.convert(seed))
+            .unwrap_or(seed)
+    }
+
+    /// Values, as sorted disjoint `(start, length)` ranges, converted into
+    /// `start..start + length`.
+    pub fn invert(&self, start: u128, length: u128) -> Ranges {
+        PiecewiseMap::from_map(self).invert(start, length)
+    }
+
+    pub fn convert_range(&self, start: u128, length: u128) -> Vec<(u128, u128)> {
+        let mut converted = vec![];
+        let mut pending = vec![(start, length)];
+        let mut tmp = vec![];
+        for converter in &self.converters {
+            for r in &pending {
+                let (mut c, mut p) = converter.convert_range(r.0, r.1);
+                converted.append(&mut c);
+                tmp.append(&mut p);
+            }
+            pending.clear();
+            pending.append(&mut tmp);
+        }
+        converted.append(&mut pending);
+        converted
+    }
+}
+
+pub type Ranges = Vec<(u128, u128)>;
+
+/// Single `destination source length` line of a map.
+pub struct RangeConverter {
+    pub dst_start: u128,
+    pub src_start: u128,
+    pub length: u128,
+}
+
+impl RangeConverter {
+    /// Both the source and the destination range must end by [`DOMAIN_END`].
+    pub fn parse(line: &str) -> IResult<&str, Self> {
+        verify(
+            map(
+                tuple((u128, space1, u128, space1, u128)),
+                |(dst_start, _, src_start, _, length)| Self {
+                    dst_start,
+                    src_start,
+                    length,
+                },
+            ),
+            |c: &Self| {
+                c.length <= DOMAIN_END
+                    && c.src_start <= DOMAIN_END - c.length
+                    && c.dst_start <= DOMAIN_END - c.length
+            },
+        )(line)
+    }
+
+    /// Source values `src_start..src_end()`, end excluded.
+    pub fn src_end(&self) -> u128 {
+        self.src_start + self.length
+    }
+
+    pub fn convert(&self, src: u128) -> Option<u128> {
+        (self.src_start..self.src_end())
+            .contains(&src)
+            .then(|| self.dst_start + (src - self.src_start))
+    }
+
+    /// Splits `start..start + length` into its converted part and the parts
+    /// left unmapped. Empty ranges are left out.
+    pub fn convert_range(&self, start: u128, length: u128) -> (Ranges, Ranges) {
+        let end = start + length;
+        let (lo, hi) = (max(start, self.src_start), min(end, self.src_end()));
+        if lo >= hi {
+            let unmapped = if length > 0 {
+                vec![(start, length)]
+            } else {
+                vec![]
+            };
+            return (vec![], unmapped);
+        }
+
+        let converted = vec![(self.dst_start + (lo - self.src_start), hi - lo)];
+        let mut unmapped = vec![];
+        if start < lo {
+            unmapped.push((start, lo - start));
+        }
+        if hi < end {
+            unmapped.push((hi, end - hi));
+        }
+        (converted, unmapped)
+    }
+}
+
+/// Converters may only map values below this, so [`PiecewiseMap`] can work
+/// with signed offsets; every value from here on maps to itself.
+pub const DOMAIN_END: u128 = 1 << 100;
+
+/// Piece of a function over `start..end` adding `offset` to its argument.
+#[derive(Clone, Copy)]
+struct Segment {
+    start: u128,
+    end: u128,
+    offset: i128,
+}
+
+/// Map made of disjoint pieces sorted by source, mapping every other value to
+/// itself. Unlike [`Map`], lookups are a binary search.
+pub struct PiecewiseMap {
+    pieces: Vec<RangeConverter>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    /// Same function as `map`, where the first converter wins where they overlap.
+    pub fn from_map(map: &Map) -> Self {
+        let mut segments = vec![Segment {
+            start: 0,
+            end: DOMAIN_END,
+            offset: 0,
+        }];
+        for c in map.converters.iter().rev() {
+            let (start, end) = (c.src_start, c.src_start + c.length);
+            let mut overlaid = Vec::with_capacity(segments.len() + 2);
+            for s in segments {
+                if s.start < start {
+                    overlaid.push(Segment {
+                        end: min(s.end, start),
+                        ..s
+                    });
+                }
+                if s.start <= start && start < s.end {
+                    overlaid.push(Segment {
+                        start,
+                        end,
+                        offset: c.dst_start as i128 - c.src_start as i128,
+                    });
+                }
+                if end < s.end {
+                    overlaid.push(Segment {
+                        start: max(s.start, end),
+                        ..s
+                    });
+                }
+            }
+            segments = overlaid;
+        }
+        Self::from_segments(segments)
+    }
+
+    /// Applies `self`, then `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> Self {
+        let next = next.segments();
+        let mut composed = Vec::new();
+        for a in self.segments() {
+            let (lo, hi) = (a.start as i128 + a.offset, a.end as i128 + a.offset);
+            let first = next.partition_point(|b| b.end as i128 <= lo);
+            for b in next[first..].iter().take_while(|b| (b.start as i128) < hi) {
+                composed.push(Segment {
+                    start: (max(lo, b.start as i128) - a.offset) as u128,
+                    end: (min(hi, b.end as i128) - a.offset) as u128,
+                    offset: a.offset + b.offset,
+                });
+            }
+        }
+        Self::from_segments(composed)
+    }
+
+    pub fn pieces(&self) -> &[RangeConverter] {
+        &self.pieces
+    }
+
+    pub fn convert(&self, src: u128) -> u128 {
+        let i = self
+            .pieces
+            .partition_point(|p| p.src_start + p.length <= src);
+        match self.pieces.get(i) {
+            Some(p) if p.src_start <= src => p.dst_start + (src - p.src_start),
+            _ => src,
+        }
+    }
+
+    /// Images, as `(start, length)`, of the pieces of `start..start + length`.
+    pub fn convert_range(&self, start: u128, length: u128) -> Ranges {
+        let end = start + length;
+        let mut converted = Vec::new();
+        let mut i = self
+            .pieces
+            .partition_point(|p| p.src_start + p.length <= start);
+        let mut pos = start;
+        while pos < end {
+            let stop = match self.pieces.get(i) {
+                Some(p) if p.src_start <= pos => {
+                    let stop = min(end, p.src_start + p.length);
+                    converted.push((p.dst_start + (pos - p.src_start), stop - pos));
+                    i += 1;
+                    stop
+                }
+                Some(p) => {
+                    let stop = min(end, p.src_start);
+                    converted.push((pos, stop - pos));
+                    stop
+                }
+                None => {
+                    converted.push((pos, end - pos));
+                    end
+                }
+            };
+            pos = stop;
+        }
+        converted
+    }
+
+    /// Values, as sorted disjoint `(start, length)` ranges, converted into
+    /// `start..start + length`.
+    pub fn invert(&self, start: u128, length: u128) -> Ranges {
+        let (lo, hi) = (start as i128, (start + length) as i128);
+        normalize(
+            self.segments()
+                .iter()
+                .filter_map(|s| {
+                    let from = max(s.start as i128, lo - s.offset);
+                    let to = min(s.end as i128, hi - s.offset);
+                    (from < to).then(|| (from as u128, (to - from) as u128))
+                })
+                .collect(),
+        )
+    }
+
+    /// Covers the whole domain, identity gaps included.
+    fn segments(&self) -> Vec<Segment> {
+        let mut segments = Vec::with_capacity(self.pieces.len() * 2 + 1);
+        let mut pos = 0;
+        for p in &self.pieces {
+            if pos < p.src_start {
+                segments.push(Segment {
+                    start: pos,
+                    end: p.src_start,
+                    offset: 0,
+                });
+            }
+            pos = p.src_start + p.length;
+            segments.push(Segment {
+                start: p.src_start,
+                end: pos,
+                offset: p.dst_start as i128 - p.src_start as i128,
+            });
+        }
+        segments.push(Segment {
+            start: pos,
+            end: DOMAIN_END,
+            offset: 0,
+        });
+        segments
+    }
+
+    fn from_segments(segments: Vec<Segment>) -> Self {
+        let mut merged: Vec<Segment> = Vec::new();
+        for s in segments.into_iter().filter(|s| s.start < s.end) {
+            match merged.last_mut() {
+                Some(last) if last.end == s.start && last.offset == s.offset => last.end = s.end,
+                _ => merged.push(s),
+            }
+        }
+        Self {
+            pieces: merged
+                .into_iter()
+                .filter(|s| s.offset != 0)
+                .map(|s| RangeConverter {
+                    dst_start: (s.start as i128 + s.offset) as u128,
+                    src_start: s.start,
+                    length: s.end - s.start,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Sorts `ranges` and merges the ones that overlap or touch.
+fn normalize(mut ranges: Ranges) -> Ranges {
+    ranges.retain(|r| r.1 > 0);
+    ranges.sort_unstable();
+    let mut merged: Ranges = Vec::with_capacity(ranges.len());
+    for (start, length) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.0 + last.1 => {
+                last.1 = max(last.0 + last.1, start + length) - last.0;
+            }
+            _ => merged.push((start, length)),
+        }
+    }
+    merged
+}
+
+/// One `destination source length` line per piece, as in the almanac.
+impl fmt::Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, p) in self.pieces.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} {} {}", p.dst_start, p.src_start, p.length)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testutil::XorShift;
+    use crate::{answer, example};
+
+    use super::{ChainError, Map, PiecewiseMap, RangeConverter, Ranges, DOMAIN_END};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day05, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day05, part1))
+    }
+
+    #[test]
+    fn test_composed() {
+        let almanac = super::Almanac::parse(example!(day05, part1)).unwrap().1;
+
+        let composed = almanac.composed();
+
+        for &seed in &almanac.seeds {
+            let folded = almanac.maps().iter().fold(seed, |v, m| m.convert(v));
+            assert_eq!(composed.convert(seed), folded);
+        }
+        assert_eq!(
+            composed.convert_range(79, 14),
+            vec![(82, 3), (46, 10), (60, 1)]
+        );
+        assert_eq!(
+            composed.to_string().lines().take(3).collect::<Vec<_>>(),
+            vec!["22 0 14", "43 14 1", "36 15 7"]
+        );
+    }
+
+    #[test]
+    fn test_seeds_for_location() {
+        let almanac = super::Almanac::parse(example!(day05, part1)).unwrap().1;
+
+        for (start, length) in [(0, 1), (35, 1), (46, 10), (50, 30), (90, 20)] {
+            let seeds = almanac.seeds_for_location(start, length);
+
+            for seed in 0..120 {
+                let location = almanac.location(seed);
+                let found = seeds.iter().any(|&(s, l)| s <= seed && seed < s + l);
+                assert_eq!(
+                    found,
+                    (start..start + length).contains(&location),
+                    "seed {}",
+                    seed
+                );
+            }
+        }
+        assert_eq!(almanac.seeds_for_location(35, 1), vec![(13, 1)]);
+    }
+
+    #[test]
+    fn test_map_invert() {
+        let almanac = super::Almanac::parse(example!(day05, part1)).unwrap().1;
+
+        for map in almanac.maps() {
+            for (start, length) in [(0, 1), (13, 5), (50, 48), (99, 10)] {
+                let values = map.invert(start, length);
+
+                for value in 0..120 {
+                    let found = values.iter().any(|&(s, l)| s <= value && value < s + l);
+                    assert_eq!(
+                        found,
+                        (start..start + length).contains(&map.convert(value)),
+                        "{}-to-{} {}",
+                        map.source,
+                        map.target,
+                        value
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_categories() {
+        let input = example!(day05, part1);
+        let almanac = super::Almanac::parse(input).unwrap().1;
+        let broken = |from: &str, to: &str| {
+            super::Almanac::parse(&input.replace(from, to))
+                .unwrap()
+                .1
+                .validate()
+        };
+
+        assert_eq!(almanac.validate(), Ok(()));
+        assert_eq!(almanac.convert("seed", "soil", 79), Ok(81));
+        assert_eq!(almanac.convert("soil", "humidity", 81), Ok(78));
+        assert_eq!(almanac.convert("seed", "location", 79), Ok(82));
+        assert_eq!(
+            almanac.convert("location", "seed", 82),
+            Err(ChainError::Gap("location".to_string()))
+        );
+        assert_eq!(
+            almanac.convert("seed", "flower", 1),
+            Err(ChainError::UnknownCategory("flower".to_string()))
+        );
+        assert_eq!(
+            broken("water-to-light", "water-to-fertilizer"),
+            Err(ChainError::Cycle("fertilizer".to_string()))
+        );
+        assert_eq!(
+            broken("water-to-light", "waters-to-light"),
+            Err(ChainError::Gap("water".to_string()))
+        );
+        assert_eq!(
+            broken("soil-to-fertilizer", "seed-to-fertilizer"),
+            Err(ChainError::Ambiguous("seed".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_domain_end() {
+        let almanac = |seed: u128, converter: String| {
+            let input = format!("seeds: {}\n\nseed-to-location map:\n{}", seed, converter);
+            super::Almanac::parse(&input)
+                .ok()
+                .map(|(_, almanac)| almanac)
+        };
+        let beyond = 1u128 << 101;
+
+        assert!(almanac(beyond, format!("7 {} 10", beyond)).is_none());
+        assert!(almanac(0, format!("{} 0 10", DOMAIN_END - 9)).is_none());
+        assert!(almanac(0, format!("0 {} 1", DOMAIN_END)).is_none());
+        assert!(almanac(0, format!("0 5 1\n0 {} 1", DOMAIN_END)).is_none());
+
+        let almanac = almanac(beyond, format!("{} 0 10", DOMAIN_END - 10)).unwrap();
+        for seed in [0, 9, 10, DOMAIN_END - 1, DOMAIN_END, beyond] {
+            let folded = almanac.maps().iter().fold(seed, |v, m| m.convert(v));
+            assert_eq!(almanac.location(seed), folded, "{}", seed);
+        }
+        assert_eq!(
+            super::part1(&format!(
+                "seeds: {}\n\nseed-to-location map:\n5 0 1",
+                beyond
+            )),
+            beyond.to_string()
+        );
+    }
+
+    #[test]
+    fn test_converter_bounds() {
+        let converter = RangeConverter {
+            dst_start: 50,
+            src_start: 98,
+            length: 2,
+        };
+
+        assert_eq!(converter.convert(97), None);
+        assert_eq!(converter.convert(99), Some(51));
+        assert_eq!(converter.convert(100), None);
+        assert_eq!(
+            converter.convert_range(96, 4),
+            (vec![(50, 2)], vec![(96, 2)])
+        );
+        assert_eq!(converter.convert_range(100, 3), (vec![], vec![(100, 3)]));
+    }
+
+    #[test]
+    fn test_exhaustive_small_domain() {
+        check_random_maps(300);
+    }
+
+    /// Slow variant of [`test_exhaustive_small_domain`], run with `--ignored`.
+    #[test]
+    #[ignore]
+    fn test_exhaustive_small_domain_thorough() {
+        check_random_maps(30_000);
+    }
+
+    /// Compares point-wise conversion of every range within a small domain to
+    /// range conversion, for `count` random maps with possibly overlapping
+    /// converters.
+    fn check_random_maps(count: usize) {
+        const DOMAIN: u128 = 24;
+        let mut rng = XorShift::new(0x2545f4914f6cdd1d);
+        let mut next = |n: u128| rng.below(n as u64) as u128;
+
+        for _ in 0..count {
+            let map = Map {
+                source: "a".to_string(),
+                target: "b".to_string(),
+                converters: (0..next(4) + 1)
+                    .map(|_| RangeConverter {
+                        dst_start: next(DOMAIN),
+                        src_start: next(DOMAIN),
+                        length: next(DOMAIN / 2),
+                    })
+                    .collect(),
+            };
+            let piecewise = PiecewiseMap::from_map(&map);
+
+            for start in 0..DOMAIN {
+                for length in 0..=DOMAIN - start {
+                    let mut expected = (start..start + length)
+                        .map(|v| map.convert(v))
+                        .collect::<Vec<_>>();
+                    expected.sort_unstable();
+                    let expand = |ranges: Ranges| {
+                        let mut points = ranges
+                            .into_iter()
+                            .flat_map(|(s, l)| s..s + l)
+                            .collect::<Vec<_>>();
+                        points.sort_unstable();
+                        points
+                    };
+
+                    assert_eq!(expand(map.convert_range(start, length)), expected);
+                    assert_eq!(expand(piecewise.convert_range(start, length)), expected);
+                }
+                assert_eq!(piecewise.convert(start), map.convert(start));
+            }
+        }
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day05, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day05, part2))
+    }
+}
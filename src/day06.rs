@@ -0,0 +1,477 @@
+use std::cmp::min;
+use std::fmt;
+use std::ops::Range;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{digit1, space0, space1};
+use nom::combinator::all_consuming;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded};
+use nom::IResult;
+
+pub fn part1(input: &str) -> String {
+    let sheet = RaceSheet::parse(input).unwrap();
+
+    margin(sheet.races(), &Accelerating { per_ms: 1 }).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let sheet = RaceSheet::parse(input).unwrap();
+
+    margin(&[sheet.kerned().unwrap()], &Accelerating { per_ms: 1 }).to_string()
+}
+
+/// Race duration and the record distance to beat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SheetError {
+    /// The sheet ends before the line with this label.
+    Missing(&'static str),
+    /// Line, with its expected label, that is not a label followed by numbers.
+    Malformed(&'static str, String),
+    /// Times and distances have different numbers of columns.
+    ColumnCount { times: usize, distances: usize },
+    /// Number, possibly kerned, that does not fit in a u64.
+    TooLarge(String),
+    /// Non-blank line after the `Distance:` line.
+    Trailing(String),
+}
+
+impl fmt::Display for SheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SheetError::Missing(label) => write!(f, "missing '{}' line", label),
+            SheetError::Malformed(label, line) => {
+                write!(f, "expected '{}' and numbers, found '{}'", label, line)
+            }
+            SheetError::ColumnCount { times, distances } => {
+                write!(f, "{} times but {} distances", times, distances)
+            }
+            SheetError::TooLarge(number) => write!(f, "{} does not fit in 64 bits", number),
+            SheetError::Trailing(line) => write!(f, "unexpected line '{}'", line),
+        }
+    }
+}
+
+impl std::error::Error for SheetError {}
+
+/// The `Time:` and `Distance:` sheet, read both as separate races and as a
+/// single race with the spaces between digits ignored.
+#[derive(Debug)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+    /// Joined digits of the time and distance lines.
+    kerned: (String, String),
+}
+
+impl RaceSheet {
+    pub fn parse(input: &str) -> Result<Self, SheetError> {
+        let mut lines = input.lines();
+        let times = labelled_line(lines.next(), "Time:")?;
+        let distances = labelled_line(lines.next(), "Distance:")?;
+        if let Some(line) = lines.find(|line| !line.trim().is_empty()) {
+            return Err(SheetError::Trailing(line.to_string()));
+        }
+        if times.len() != distances.len() {
+            return Err(SheetError::ColumnCount {
+                times: times.len(),
+                distances: distances.len(),
+            });
+        }
+
+        let races = times
+            .iter()
+            .zip(&distances)
+            .map(|(time, distance)| {
+                Ok(Race {
+                    time: number(time)?,
+                    distance: number(distance)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let kerned = (times.concat(), distances.concat());
+
+        Ok(Self { races, kerned })
+    }
+
+    /// Every column as its own race.
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    /// The single race read by joining the digits of each line. The joined
+    /// numbers may not fit in a u64 even when every column does.
+    pub fn kerned(&self) -> Result<Race, SheetError> {
+        Ok(Race {
+            time: number(&self.kerned.0)?,
+            distance: number(&self.kerned.1)?,
+        })
+    }
+}
+
+/// Digit groups of `line`, which must start with `label`.
+fn labelled_line<'a>(
+    line: Option<&'a str>,
+    label: &'static str,
+) -> Result<Vec<&'a str>, SheetError> {
+    let line = line.ok_or(SheetError::Missing(label))?;
+    all_consuming(|i| parse_data_line(i, label))(line)
+        .map(|(_, digits)| digits)
+        .map_err(|_| SheetError::Malformed(label, line.to_string()))
+}
+
+/// Digit groups following `label`.
+pub fn parse_data_line<'a>(line: &'a str, label: &'static str) -> IResult<&'a str, Vec<&'a str>> {
+    preceded(
+        tag(label),
+        delimited(space0, separated_list1(space1, digit1), space0),
+    )(line)
+}
+
+fn number(digits: &str) -> Result<u64, SheetError> {
+    digits
+        .parse()
+        .map_err(|_| SheetError::TooLarge(digits.to_string()))
+}
+
+/// Product of the number of ways to win each race.
+pub fn margin(races: &[Race], physics: &dyn Physics) -> u64 {
+    races
+        .iter()
+        .map(|race| physics.winning(race.time, race.distance))
+        .fold(1u64, |seed, r| seed * (r.end - r.start))
+}
+
+/// Hold times that beat `distance` in a race of `time`, computed exactly.
+pub fn solve(time: u64, distance: u64) -> Range<u64> {
+    let (t, d) = (time as u128, distance as u128);
+    let wins = |hold: u128| hold * (t - hold) > d;
+
+    // Both roots of hold * (time - hold) = distance lie around (t -+ sqrt(disc)) / 2.
+    let Some(disc) = (t * t).checked_sub(4 * d) else {
+        return 0..0;
+    };
+    let mut first = (t - disc.isqrt()) / 2;
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    while first <= t / 2 && !wins(first) {
+        first += 1;
+    }
+    if first > t / 2 {
+        return 0..0;
+    }
+
+    // Winning holds are symmetric around time / 2.
+    first as u64..(t - first + 1) as u64
+}
+
+/// How far the boat gets for a given button hold.
+pub trait Physics {
+    /// Distance covered in a race of `time` ms when holding for `hold` ms.
+    fn distance(&self, hold: u64, time: u64) -> u128;
+
+    /// Hold times that beat `record`. Defaults to [`search`], which needs the
+    /// distance to rise and then fall as the hold grows.
+    fn winning(&self, time: u64, record: u64) -> Range<u64> {
+        search(self, time, record)
+    }
+}
+
+/// Speed grows by `per_ms` for every millisecond held; the puzzle uses 1.
+pub struct Accelerating {
+    pub per_ms: u64,
+}
+
+/// Like [`Accelerating`], but the speed never exceeds `max_speed`.
+pub struct SpeedCap {
+    pub per_ms: u64,
+    pub max_speed: u64,
+}
+
+/// Like [`Accelerating`], but the boat loses `decay` speed every millisecond
+/// it travels, until it stops.
+pub struct Friction {
+    pub per_ms: u64,
+    pub decay: u64,
+}
+
+impl Physics for Accelerating {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        self.per_ms as u128 * hold as u128 * (time - hold) as u128
+    }
+
+    fn winning(&self, time: u64, record: u64) -> Range<u64> {
+        if self.per_ms == 0 {
+            return 0..0;
+        }
+        // per_ms * x > record exactly when x > record / per_ms.
+        solve(time, record / self.per_ms)
+    }
+}
+
+impl Physics for SpeedCap {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        let speed = min(self.per_ms as u128 * hold as u128, self.max_speed as u128);
+        speed * (time - hold) as u128
+    }
+}
+
+impl Physics for Friction {
+    fn distance(&self, hold: u64, time: u64) -> u128 {
+        let speed = self.per_ms as u128 * hold as u128;
+        let travel = (time - hold) as u128;
+        if self.decay == 0 {
+            return speed * travel;
+        }
+        let decay = self.decay as u128;
+        let moving = min(travel, speed.div_ceil(decay));
+        moving * speed - decay * moving * moving.saturating_sub(1) / 2
+    }
+}
+
+/// Winning hold times for a distance that rises to a peak and then falls,
+/// found by binary searches for the peak and both boundaries.
+pub fn search<P: Physics + ?Sized>(physics: &P, time: u64, record: u64) -> Range<u64> {
+    let f = |hold| physics.distance(hold, time);
+    let record = record as u128;
+
+    let peak = partition_point(0, time, |h| f(h) < f(h + 1));
+    if f(peak) <= record {
+        return 0..0;
+    }
+    let first = partition_point(0, peak, |h| f(h) <= record);
+    let end = partition_point(peak, time + 1, |h| f(h) > record);
+    first..end
+}
+
+/// First value in `lo..hi` for which `pred` is false, `hi` if there is none;
+/// `pred` must hold for a prefix of the range.
+fn partition_point(mut lo: u64, mut hi: u64, pred: impl Fn(u64) -> bool) -> u64 {
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Parses `accelerating:<per_ms>`, `cap:<per_ms>:<max_speed>` or
+/// `friction:<per_ms>:<decay>`.
+pub fn parse_physics(spec: &str) -> Option<Box<dyn Physics>> {
+    let parts = spec.split(':').collect::<Vec<_>>();
+    let numbers = parts[1..]
+        .iter()
+        .map(|n| n.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    match (parts[0], &numbers[..]) {
+        ("accelerating", &[per_ms]) => Some(Box::new(Accelerating { per_ms })),
+        ("cap", &[per_ms, max_speed]) => Some(Box::new(SpeedCap { per_ms, max_speed })),
+        ("friction", &[per_ms, decay]) => Some(Box::new(Friction { per_ms, decay })),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use super::{Accelerating, Friction, Physics, Race, RaceSheet, SheetError, SpeedCap};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day06, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day06, part1))
+    }
+
+    #[test]
+    fn test_race_sheet() {
+        let sheet = RaceSheet::parse(example!(day06, part1)).unwrap();
+
+        assert_eq!(
+            sheet.races(),
+            [
+                Race {
+                    time: 7,
+                    distance: 9
+                },
+                Race {
+                    time: 15,
+                    distance: 40
+                },
+                Race {
+                    time: 30,
+                    distance: 200
+                },
+            ]
+        );
+        assert_eq!(
+            sheet.kerned(),
+            Ok(Race {
+                time: 71530,
+                distance: 940200
+            })
+        );
+
+        let sheet = RaceSheet::parse("Time: 1 02\r\nDistance:3   04  \r\n\n").unwrap();
+        assert_eq!(
+            sheet.kerned(),
+            Ok(Race {
+                time: 102,
+                distance: 304
+            })
+        );
+    }
+
+    #[test]
+    fn test_race_sheet_errors() {
+        let parse = |input| RaceSheet::parse(input).unwrap_err();
+
+        assert_eq!(parse("Time: 7 15"), SheetError::Missing("Distance:"));
+        assert_eq!(
+            parse("Tim: 7\nDistance: 9"),
+            SheetError::Malformed("Time:", "Tim: 7".to_string())
+        );
+        assert_eq!(
+            parse("Time: 7 x\nDistance: 9"),
+            SheetError::Malformed("Time:", "Time: 7 x".to_string())
+        );
+        assert_eq!(
+            parse("Time: 7 15\nDistance: 9"),
+            SheetError::ColumnCount {
+                times: 2,
+                distances: 1
+            }
+        );
+        assert_eq!(
+            parse("Time: 7\nDistance: 9\nTime: 1"),
+            SheetError::Trailing("Time: 1".to_string())
+        );
+        assert_eq!(
+            parse("Time: 40000000000000000000\nDistance: 1"),
+            SheetError::TooLarge("40000000000000000000".to_string())
+        );
+
+        let sheet = RaceSheet::parse("Time: 4000000000 4000000000 4\nDistance: 1 2 3").unwrap();
+        assert_eq!(sheet.races().len(), 3);
+        assert_eq!(
+            sheet.kerned(),
+            Err(SheetError::TooLarge("400000000040000000004".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_solve_matches_brute_force() {
+        for time in 0..60u64 {
+            for distance in 0..(time * time / 4 + 3) {
+                let winning = (0..=time).filter(|h| h * (time - h) > distance);
+
+                let r = super::solve(time, distance);
+
+                assert_eq!(
+                    r.end - r.start,
+                    winning.count() as u64,
+                    "{} {}",
+                    time,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_exact_ties() {
+        let time = 1 << 40;
+        let hold = (1 << 20) + 3;
+        let tie = hold * (time - hold);
+
+        assert_eq!(super::solve(time, tie), hold + 1..time - hold);
+        assert_eq!(super::solve(time, tie - 1), hold..time - hold + 1);
+
+        let time = 1 << 31;
+        let peak = time / 2 * (time / 2);
+        assert_eq!(super::solve(time, peak - 1), time / 2..time / 2 + 1);
+        assert_eq!(super::solve(time, peak), 0..0);
+
+        // Beyond f64 precision: the tie at 1 must not win, 2..=time-2 do.
+        let time = (1 << 60) + 1;
+        assert_eq!(super::solve(time, time - 1), 2..time - 1);
+        assert_eq!(super::solve(u64::MAX, 0), 1..u64::MAX);
+    }
+
+    #[test]
+    fn test_physics_models() {
+        let models: [Box<dyn Physics>; 5] = [
+            Box::new(Accelerating { per_ms: 3 }),
+            Box::new(SpeedCap {
+                per_ms: 1,
+                max_speed: 7,
+            }),
+            Box::new(SpeedCap {
+                per_ms: 4,
+                max_speed: 30,
+            }),
+            Box::new(Friction {
+                per_ms: 1,
+                decay: 1,
+            }),
+            Box::new(Friction {
+                per_ms: 5,
+                decay: 2,
+            }),
+        ];
+        for physics in &models {
+            for time in 0..40u64 {
+                let best = (0..=time).map(|h| physics.distance(h, time)).max().unwrap();
+                for record in 0..best as u64 + 2 {
+                    let winning = (0..=time)
+                        .filter(|&h| physics.distance(h, time) > record as u128)
+                        .collect::<Vec<_>>();
+
+                    let r = physics.winning(time, record);
+
+                    assert_eq!(
+                        r.clone().collect::<Vec<_>>(),
+                        winning,
+                        "{} {}",
+                        time,
+                        record
+                    );
+                    assert_eq!(super::search(physics.as_ref(), time, record), r);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_margin_with_variant_rules() {
+        let sheet = RaceSheet::parse(example!(day06, part1)).unwrap();
+        let margin =
+            |spec| super::margin(sheet.races(), super::parse_physics(spec).unwrap().as_ref());
+
+        assert_eq!(margin("accelerating:1"), 288);
+        assert_eq!(margin("cap:1:5"), 0);
+        assert_eq!(margin("cap:1:12"), 4 * 8 * 3);
+        assert!(super::parse_physics("cap:1").is_none());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day06, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day06, part2))
+    }
+}
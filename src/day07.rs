@@ -0,0 +1,358 @@
+use std::fmt;
+
+use nom::character::complete::{alphanumeric1, space1, u32};
+use nom::combinator::map;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+pub fn part1(input: &str) -> String {
+    let records = input
+        .lines()
+        .map(|line| Record::parse(line).unwrap().1)
+        .collect::<Vec<_>>();
+
+    Ruleset::standard().winnings(&records).unwrap().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let records = input
+        .lines()
+        .map(|line| Record::parse(line).unwrap().1)
+        .collect::<Vec<_>>();
+
+    Ruleset::jokers().winnings(&records).unwrap().to_string()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RuleError {
+    /// The card order lists this label twice.
+    DuplicateRank(char),
+    /// Wild label missing from the card order.
+    UnknownWild(char),
+    ZeroHandSize,
+    /// Ruleset spec that is not `<order>:<wild>:<size>`.
+    InvalidSpec(String),
+    /// Hand holding a label missing from the card order.
+    UnknownCard(String, char),
+    /// Hand with the wrong number of cards.
+    WrongSize(String),
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleError::DuplicateRank(c) => write!(f, "card '{}' ranked twice", c),
+            RuleError::UnknownWild(c) => write!(f, "wild card '{}' is not ranked", c),
+            RuleError::ZeroHandSize => write!(f, "hands must hold at least one card"),
+            RuleError::InvalidSpec(spec) => {
+                write!(f, "expected '<order>:<wild>:<size>', found '{}'", spec)
+            }
+            RuleError::UnknownCard(hand, c) => {
+                write!(f, "hand {} holds unknown card '{}'", hand, c)
+            }
+            RuleError::WrongSize(hand) => write!(f, "hand {} has the wrong number of cards", hand),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+/// Card order, wild cards and hand size of a Camel Cards variant.
+#[derive(Debug, Clone)]
+pub struct Ruleset {
+    /// Card labels, weakest first.
+    order: Vec<char>,
+    wild: Vec<char>,
+    hand_size: usize,
+}
+
+impl Ruleset {
+    pub fn new(order: &str, wild: &str, hand_size: usize) -> Result<Self, RuleError> {
+        let order = order.chars().collect::<Vec<_>>();
+        if let Some(i) = (1..order.len()).find(|&i| order[..i].contains(&order[i])) {
+            return Err(RuleError::DuplicateRank(order[i]));
+        }
+        let wild = wild.chars().collect::<Vec<_>>();
+        if let Some(c) = wild.iter().find(|c| !order.contains(c)) {
+            return Err(RuleError::UnknownWild(*c));
+        }
+        if hand_size == 0 {
+            return Err(RuleError::ZeroHandSize);
+        }
+        Ok(Self {
+            order,
+            wild,
+            hand_size,
+        })
+    }
+
+    /// The rules of part 1.
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", "", 5).unwrap()
+    }
+
+    /// The rules of part 2: `J` is the weakest card and wild.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J", 5).unwrap()
+    }
+
+    /// Parses `<order>:<wild>:<size>`, e.g. `J23456789TQKA:J:5`.
+    pub fn parse(spec: &str) -> Result<Self, RuleError> {
+        let invalid = || RuleError::InvalidSpec(spec.to_string());
+        match spec.split(':').collect::<Vec<_>>()[..] {
+            [order, wild, size] => Self::new(order, wild, size.parse().map_err(|_| invalid())?),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Rank of every card of `hand`, 0 being the weakest.
+    pub fn strengths(&self, hand: &Hand) -> Result<Vec<usize>, RuleError> {
+        if hand.0.len() != self.hand_size {
+            return Err(RuleError::WrongSize(hand.to_string()));
+        }
+        hand.0
+            .iter()
+            .map(|c| {
+                self.order
+                    .iter()
+                    .position(|o| o == c)
+                    .ok_or_else(|| RuleError::UnknownCard(hand.to_string(), *c))
+            })
+            .collect()
+    }
+
+    /// Best kind `hand` can be made into by substituting its wild cards.
+    pub fn kind(&self, hand: &Hand) -> HandKind {
+        let mut counts: Vec<(char, usize)> = Vec::new();
+        let mut wilds = 0;
+        for c in hand.0.iter() {
+            if self.wild.contains(c) {
+                wilds += 1;
+            } else if let Some(entry) = counts.iter_mut().find(|(label, _)| label == c) {
+                entry.1 += 1;
+            } else {
+                counts.push((*c, 1));
+            }
+        }
+
+        // A wild card is never worth more as a label the hand does not hold,
+        // unless the hand holds nothing else.
+        if counts.is_empty() {
+            return HandKind::classify(vec![wilds]);
+        }
+        let mut counts = counts.into_iter().map(|(_, n)| n).collect::<Vec<_>>();
+        best_substitution(&mut counts, wilds, 0)
+    }
+
+    /// Sort key of `hand`: its kind, then the strength of each card in order.
+    pub fn key(&self, hand: &Hand) -> Result<(HandKind, Vec<usize>), RuleError> {
+        Ok((self.kind(hand), self.strengths(hand)?))
+    }
+
+    /// Total winnings: every bid times the rank of its hand, 1 being the weakest.
+    pub fn winnings(&self, records: &[Record]) -> Result<u32, RuleError> {
+        let mut keyed = records
+            .iter()
+            .map(|r| Ok((self.key(&r.hand)?, r.bid)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        keyed.sort();
+
+        Ok(keyed
+            .iter()
+            .enumerate()
+            .map(|(i, (_, bid))| bid * (i as u32 + 1))
+            .sum())
+    }
+}
+
+/// Best kind over every way of adding `wilds` cards to the groups
+/// `counts[from..]`.
+fn best_substitution(counts: &mut [usize], wilds: usize, from: usize) -> HandKind {
+    if wilds == 0 {
+        return HandKind::classify(counts.to_vec());
+    }
+    (from..counts.len())
+        .map(|i| {
+            counts[i] += 1;
+            let kind = best_substitution(counts, wilds - 1, i);
+            counts[i] -= 1;
+            kind
+        })
+        .max()
+        .unwrap()
+}
+
+/// Card labels as dealt.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand(pub Vec<char>);
+
+impl Hand {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(alphanumeric1, |labels: &str| Self(labels.chars().collect()))(input)
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy)]
+pub enum HandKind {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandKind {
+    /// Kind of a hand whose cards fall into groups of equal labels of the
+    /// given sizes. Groups larger than five count as five of a kind.
+    pub fn classify(mut counts: Vec<usize>) -> Self {
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match counts[..] {
+            [n, ..] if n >= 5 => HandKind::FiveOfAKind,
+            [4, ..] => HandKind::FourOfAKind,
+            [3, n, ..] if n >= 2 => HandKind::FullHouse,
+            [3, ..] => HandKind::ThreeOfAKind,
+            [2, 2, ..] => HandKind::TwoPair,
+            [2, ..] => HandKind::OnePair,
+            _ => HandKind::HighCard,
+        }
+    }
+}
+
+/// Hand and its bid, one input line.
+pub struct Record {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+impl Record {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(separated_pair(Hand::parse, space1, u32), |(hand, bid)| {
+            Self { hand, bid }
+        })(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    use super::{Hand, HandKind, RuleError, Ruleset};
+
+    fn hand(labels: &str) -> Hand {
+        Hand(labels.chars().collect())
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day07, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day07, part1))
+    }
+
+    #[test]
+    fn test_wild_kinds() {
+        let rules = Ruleset::jokers();
+
+        assert_eq!(rules.kind(&hand("32T3K")), HandKind::OnePair);
+        assert_eq!(rules.kind(&hand("T55J5")), HandKind::FourOfAKind);
+        assert_eq!(rules.kind(&hand("KTJJT")), HandKind::FourOfAKind);
+        assert_eq!(rules.kind(&hand("2345J")), HandKind::OnePair);
+        assert_eq!(rules.kind(&hand("2233J")), HandKind::FullHouse);
+        assert_eq!(rules.kind(&hand("JJJJJ")), HandKind::FiveOfAKind);
+        assert_eq!(Ruleset::standard().kind(&hand("KTJJT")), HandKind::TwoPair);
+    }
+
+    #[test]
+    fn test_kind_matches_every_substitution() {
+        let alphabet = ['J', 'Q', '2', '3', 'A'];
+        let rules = Ruleset::new("JQ23A", "JQ", 5).unwrap();
+        let plain = Ruleset::new("JQ23A", "", 5).unwrap();
+        let substitutes = |c: char| match c {
+            'J' | 'Q' => vec!['2', '3', 'A'],
+            c => vec![c],
+        };
+
+        for n in 0..alphabet.len().pow(5) {
+            let labels = (0..5)
+                .map(|i| alphabet[n / alphabet.len().pow(i) % alphabet.len()])
+                .collect::<Vec<_>>();
+
+            let mut candidates = vec![Vec::new()];
+            for &c in labels.iter() {
+                candidates = candidates
+                    .into_iter()
+                    .flat_map(|prefix: Vec<char>| {
+                        substitutes(c).into_iter().map(move |s| {
+                            let mut next = prefix.clone();
+                            next.push(s);
+                            next
+                        })
+                    })
+                    .collect();
+            }
+            let expected = candidates
+                .into_iter()
+                .map(|c| plain.kind(&Hand(c)))
+                .max()
+                .unwrap();
+
+            assert_eq!(rules.kind(&Hand(labels.clone())), expected, "{:?}", labels);
+        }
+    }
+
+    #[test]
+    fn test_custom_ruleset() {
+        let rules = Ruleset::parse("23456789TJQKA:2:3").unwrap();
+        let records = ["A2K 1", "KKQ 10", "T98 100", "222 1000"]
+            .iter()
+            .map(|line| super::Record::parse(line).unwrap().1)
+            .collect::<Vec<_>>();
+
+        // T98 < KKQ < A2K, a pair led by an ace < 222, three wild cards
+        assert_eq!(rules.winnings(&records), Ok(100 + 20 + 3 + 4000));
+    }
+
+    #[test]
+    fn test_ruleset_errors() {
+        assert_eq!(
+            Ruleset::new("23452", "", 5).unwrap_err(),
+            RuleError::DuplicateRank('2')
+        );
+        assert_eq!(
+            Ruleset::new("2345", "J", 5).unwrap_err(),
+            RuleError::UnknownWild('J')
+        );
+        assert_eq!(
+            Ruleset::parse("2345:J").unwrap_err(),
+            RuleError::InvalidSpec("2345:J".to_string())
+        );
+        assert_eq!(
+            Ruleset::standard().strengths(&hand("2345X")),
+            Err(RuleError::UnknownCard("2345X".to_string(), 'X'))
+        );
+        assert_eq!(
+            Ruleset::standard().strengths(&hand("2345")),
+            Err(RuleError::WrongSize("2345".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day07, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day07, part2))
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until};
+use nom::character::complete::{char, line_ending, space1};
+use nom::combinator::map;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, pair, separated_pair};
+use nom::IResult;
+
+pub fn part1(input: &str) -> String {
+    let (route, map) = parse_input(input);
+
+    route_length(&map, &route, "AAA", |p| p == "ZZZ").to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let (route, map) = parse_input(input);
+
+    map.starting_points()
+        .map(|start| route_length(&map, &route, start, |p| p.ends_with("Z")))
+        .fold(1, lcm)
+        .to_string()
+}
+
+/// Parses the left/right instructions and the network.
+pub fn parse_input(input: &str) -> (Vec<char>, Map) {
+    separated_pair(
+        many1(alt((char('R'), char('L')))),
+        pair(line_ending, line_ending),
+        Map::parse,
+    )(input)
+    .unwrap()
+    .1
+}
+
+fn gcd(mut n: usize, mut m: usize) -> usize {
+    assert!(n != 0 && m != 0);
+    while m != 0 {
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+        m %= n;
+    }
+    n
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    (a * b) / gcd(a, b)
+}
+
+/// Number of steps from `start` until `end` accepts the current node.
+pub fn route_length(
+    map: &Map,
+    route: &[char],
+    start: &str,
+    mut end: impl FnMut(&str) -> bool,
+) -> usize {
+    let mut counter = 0;
+    let mut current_point = start;
+    while !end(current_point) {
+        match route[counter % route.len()] {
+            'R' => current_point = &map.point(current_point).right,
+            'L' => current_point = &map.point(current_point).left,
+            _ => unreachable!(),
+        }
+        counter += 1;
+    }
+    counter
+}
+
+/// Network of nodes, each with a left and a right neighbour.
+pub struct Map {
+    nodes: HashMap<String, MapPoint>,
+}
+
+impl Map {
+    /// Parses one `AAA = (BBB, CCC)` line per node.
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_list1(
+                line_ending,
+                separated_pair(
+                    take_until(" "),
+                    separated_pair(space1, tag("="), space1),
+                    delimited(
+                        char('('),
+                        separated_pair(take_until(","), tag(", "), take_until(")")),
+                        char(')'),
+                    ),
+                ),
+            ),
+            |a| Self {
+                nodes: a
+                    .iter()
+                    .map(|(name, (left, right))| {
+                        (
+                            String::from(*name),
+                            MapPoint {
+                                left: String::from(*left),
+                                right: String::from(*right),
+                            },
+                        )
+                    })
+                    .collect::<HashMap<String, MapPoint>>(),
+            },
+        )(input)
+    }
+
+    /// Node called `name`; panics if there is none.
+    pub fn point(&self, name: &str) -> &MapPoint {
+        self.nodes.get(name).unwrap()
+    }
+
+    /// Nodes ending with `A`, where ghosts start.
+    pub fn starting_points(&self) -> impl Iterator<Item = &str> {
+        self.nodes
+            .keys()
+            .filter(|&k| k.ends_with("A"))
+            .map(|k| k.as_str())
+    }
+}
+
+/// Left and right neighbours of a node.
+pub struct MapPoint {
+    pub left: String,
+    pub right: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = example!(day08, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day08, part1))
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let input = example!(day08, part1_ex2);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day08, part1_ex2))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day08, part2);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day08, part2))
+    }
+}
@@ -0,0 +1,82 @@
+use nom::character::complete::{i64, line_ending, space1};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+pub fn part1(input: &str) -> String {
+    let inputs = parse_input(input).unwrap().1;
+
+    inputs
+        .iter()
+        .map(|seq| predict(seq))
+        .sum::<i64>()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let inputs = parse_input(input).unwrap().1;
+
+    inputs
+        .iter()
+        .map(|seq| predict_back(seq))
+        .sum::<i64>()
+        .to_string()
+}
+
+/// Parses one sequence per line.
+pub fn parse_input(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
+    separated_list1(line_ending, separated_list1(space1, i64))(input)
+}
+
+fn derive(seq: &[i64]) -> Vec<i64> {
+    seq.iter()
+        .zip(seq[1..].iter())
+        .map(|(a, b)| b - a)
+        .collect()
+}
+
+/// Extrapolates the next value of the sequence.
+pub fn predict(seq: &[i64]) -> i64 {
+    if seq.iter().all(|&x| x == 0) {
+        return 0;
+    }
+
+    let d = derive(seq);
+    let n = predict(&d);
+
+    seq.last().unwrap() + n
+}
+
+/// Extrapolates the value preceding the sequence.
+pub fn predict_back(seq: &[i64]) -> i64 {
+    if seq.iter().all(|&x| x == 0) {
+        return 0;
+    }
+
+    let d = derive(seq);
+    let n = predict_back(&d);
+
+    seq.first().unwrap() - n
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day09, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day09, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day09, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day09, part2))
+    }
+}
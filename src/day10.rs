@@ -0,0 +1,233 @@
+pub fn part1(input: &str) -> String {
+    let map = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    (find_longest_loop(&map).len() / 2).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let map = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let size = (map[0].len(), map.len());
+
+    let longest_loop = find_longest_loop(&map);
+
+    let mut map = vec![vec!['.'; size.0]; size.1];
+    for (x, y, ch) in &longest_loop {
+        map[*y][*x] = *ch;
+    }
+
+    map.iter()
+        .map(|line| score_line(line))
+        .sum::<usize>()
+        .to_string()
+}
+
+/// Heading of a step along the pipes.
+#[derive(Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// Tiles `(x, y, pipe)` of the loop through `S`, with `S` replaced by its pipe.
+pub fn find_longest_loop(map: &[Vec<char>]) -> Vec<(usize, usize, char)> {
+    let (sx, sy) = map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| line.iter().enumerate().map(move |(x, &ch)| (x, y, ch)))
+        .find(|(_, _, ch)| *ch == 'S')
+        .map(|(x, y, _)| (x, y))
+        .unwrap();
+
+    [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ]
+    .iter()
+    .filter_map(|&initial_dir| {
+        let mut path = vec![];
+        let mut cx = sx;
+        let mut cy = sy;
+        let mut dir = initial_dir;
+        while let Some((nx, ny, ch)) = step(map, cx, cy, dir) {
+            if ch == 'S' {
+                path.push((nx, ny, start_subs(initial_dir, dir)));
+                return Some(path);
+            }
+            path.push((nx, ny, ch));
+            dir = match (dir, ch) {
+                (Direction::North, '|') => Direction::North,
+                (Direction::South, '|') => Direction::South,
+                (Direction::North, '7') => Direction::West,
+                (Direction::North, 'F') => Direction::East,
+                (Direction::South, 'J') => Direction::West,
+                (Direction::South, 'L') => Direction::East,
+                (Direction::East, '-') => Direction::East,
+                (Direction::West, '-') => Direction::West,
+                (Direction::East, '7') => Direction::South,
+                (Direction::East, 'J') => Direction::North,
+                (Direction::West, 'F') => Direction::South,
+                (Direction::West, 'L') => Direction::North,
+                _ => unreachable!(),
+            };
+            cx = nx;
+            cy = ny;
+        }
+        None
+    })
+    .next_back()
+    .unwrap()
+}
+
+fn start_subs(initial_dir: Direction, final_dir: Direction) -> char {
+    match (initial_dir, final_dir) {
+        (Direction::North, Direction::North) | (Direction::South, Direction::South) => '|',
+        (Direction::East, Direction::East) | (Direction::West, Direction::West) => '-',
+        (Direction::North, Direction::East) => 'J',
+        (Direction::North, Direction::West) => 'L',
+        (Direction::South, Direction::East) => '7',
+        (Direction::South, Direction::West) => 'F',
+
+        (Direction::East, Direction::South) => 'L',
+        (Direction::West, Direction::South) => 'J',
+        (Direction::East, Direction::North) => 'F',
+        (Direction::West, Direction::North) => '7',
+        _ => unreachable!(),
+    }
+}
+
+fn score_line(line: &[char]) -> usize {
+    let mut score = 0;
+    let mut crossings = 0;
+    for c in line {
+        match c {
+            '.' if crossings % 2 != 0 => {
+                score += 1;
+            }
+            '|' | 'F' | '7' => {
+                crossings += 1;
+            }
+            _ => {}
+        }
+    }
+    score
+}
+
+fn step(map: &[Vec<char>], sx: usize, sy: usize, dir: Direction) -> Option<(usize, usize, char)> {
+    match dir {
+        Direction::North if sy == 0 => return None,
+        Direction::South if sy == map.len() - 1 => return None,
+        Direction::East if sx == map.first().unwrap().len() - 1 => return None,
+        Direction::West if sx == 0 => return None,
+        _ => {}
+    };
+
+    let (nx, ny) = match (map[sy][sx], dir) {
+        ('|', Direction::North)
+        | ('S', Direction::North)
+        | ('L', Direction::North)
+        | ('J', Direction::North) => (sx, sy - 1),
+        ('|', Direction::South)
+        | ('S', Direction::South)
+        | ('7', Direction::South)
+        | ('F', Direction::South) => (sx, sy + 1),
+        ('-', Direction::East)
+        | ('S', Direction::East)
+        | ('L', Direction::East)
+        | ('F', Direction::East) => (sx + 1, sy),
+        ('-', Direction::West)
+        | ('S', Direction::West)
+        | ('J', Direction::West)
+        | ('7', Direction::West) => (sx - 1, sy),
+        _ => return None,
+    };
+
+    match (dir, map[ny][nx]) {
+        (Direction::North, '|')
+        | (Direction::North, 'S')
+        | (Direction::North, '7')
+        | (Direction::North, 'F') => Some((nx, ny, map[ny][nx])),
+        (Direction::South, '|')
+        | (Direction::South, 'S')
+        | (Direction::South, 'J')
+        | (Direction::South, 'L') => Some((nx, ny, map[ny][nx])),
+        (Direction::East, '-')
+        | (Direction::East, 'S')
+        | (Direction::East, 'J')
+        | (Direction::East, '7') => Some((nx, ny, map[ny][nx])),
+        (Direction::West, '-')
+        | (Direction::West, 'S')
+        | (Direction::West, 'L')
+        | (Direction::West, 'F') => Some((nx, ny, map[ny][nx])),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = example!(day10, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day10, part1))
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let input = example!(day10, part1_ex2);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day10, part1_ex2))
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let input = example!(day10, part2);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day10, part2))
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        let input = example!(day10, part2_ex2);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day10, part2_ex2))
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        let input = example!(day10, part2_ex3);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day10, part2_ex3))
+    }
+
+    #[test]
+    fn test_part2_ex4() {
+        let input = example!(day10, part2_ex4);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day10, part2_ex4))
+    }
+}
@@ -0,0 +1,115 @@
+pub fn part1(input: &str) -> String {
+    total_distance(input, 2).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    total_distance(input, 1000000).to_string()
+}
+
+/// Sum of the distances between every pair of galaxies once empty rows and
+/// columns are replaced by `expand_size` of them.
+pub fn total_distance(input: &str, expand_size: usize) -> usize {
+    let mut universe = load_universe(input);
+    expand_universe(&mut universe, expand_size);
+    universe
+        .iter()
+        .enumerate()
+        .map(|(i, g)| {
+            universe
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j > i)
+                .map(|(_, g2)| distance(g, g2))
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+}
+
+/// Galaxy position, `x` being the column.
+pub struct Galaxy {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Manhattan distance between two galaxies.
+pub fn distance(a: &Galaxy, b: &Galaxy) -> usize {
+    ((a.x as i128 - b.x as i128).abs() + (a.y as i128 - b.y as i128).abs()) as usize
+}
+
+/// Galaxies of the image, before expansion.
+pub fn load_universe(input: &str) -> Vec<Galaxy> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, ch)| *ch == '#')
+                .map(move |(x, _)| Galaxy { x, y })
+        })
+        .collect()
+}
+
+/// Replaces every empty row and column with `expand_size` of them.
+pub fn expand_universe(universe: &mut [Galaxy], expand_size: usize) {
+    let mut i = 0;
+    while i < universe.iter().map(|g| g.x).max().unwrap() {
+        let next = universe
+            .iter()
+            .filter(|g| i < g.x)
+            .map(|g| g.x)
+            .min()
+            .unwrap();
+        let expand_k = (next - i - 1) * (expand_size - 1);
+        for g in universe.iter_mut().filter(|g| i < g.x) {
+            g.x += expand_k
+        }
+        i = next + expand_k;
+    }
+    let mut i = 0;
+    while i < universe.iter().map(|g| g.y).max().unwrap() {
+        let next = universe
+            .iter()
+            .filter(|g| i < g.y)
+            .map(|g| g.y)
+            .min()
+            .unwrap();
+        let expand_k = (next - i - 1) * (expand_size - 1);
+        for g in universe.iter_mut().filter(|g| i < g.y) {
+            g.y += expand_k
+        }
+        i = next + expand_k;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day11, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day11, part1))
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let input = example!(day11, part1);
+
+        let output = super::total_distance(input, 10).to_string();
+
+        assert_eq!(output, answer!(day11, part2))
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        let input = example!(day11, part1);
+
+        let output = super::total_distance(input, 100).to_string();
+
+        assert_eq!(output, answer!(day11, part2_ex2))
+    }
+}
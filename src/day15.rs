@@ -0,0 +1,170 @@
+use nom::branch::alt;
+use nom::character::complete::{alpha1, char, u8};
+use nom::combinator::{map, success};
+use nom::sequence::pair;
+use nom::IResult;
+
+pub fn part1(input: &str) -> String {
+    input
+        .split(",")
+        .map(|s| hash(s) as usize)
+        .sum::<usize>()
+        .to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut boxes = Vec::with_capacity(256);
+    for box_number in 0..boxes.capacity() {
+        boxes.push(LensBox::new(box_number));
+    }
+
+    for step in input.split(",").map(|s| Step::parse(s).unwrap().1) {
+        apply(&step, &mut boxes[step.box_number()]);
+    }
+
+    boxes
+        .iter()
+        .filter(|&b| !b.lens_slots.is_empty())
+        .map(|b| b.focusing_power())
+        .sum::<usize>()
+        .to_string()
+}
+
+/// Holiday ASCII String Helper algorithm.
+pub fn hash(s: &str) -> u8 {
+    s.chars()
+        .map(|c| c as u32)
+        .fold(0, |a, v| (a + v) * 17 % 256) as u8
+}
+
+/// Lens with its label and focal length.
+pub struct Lens {
+    pub label: String,
+    pub focal_length: u8,
+}
+
+/// Box holding lenses in the order they were inserted.
+pub struct LensBox {
+    pub box_number: usize,
+    pub lens_slots: Vec<Lens>,
+}
+
+impl LensBox {
+    /// Empty box.
+    pub fn new(box_number: usize) -> Self {
+        Self {
+            box_number,
+            lens_slots: Vec::new(),
+        }
+    }
+
+    /// Sum of the focusing power of every lens in the box.
+    pub fn focusing_power(&self) -> usize {
+        self.lens_slots
+            .iter()
+            .enumerate()
+            .map(|(slot_id, lens)| {
+                (self.box_number + 1) * (slot_id + 1) * lens.focal_length as usize
+            })
+            .sum()
+    }
+
+    fn remove_lens(&mut self, label: &str) {
+        if let Some(idx) = self
+            .lens_slots
+            .iter()
+            .enumerate()
+            .find(|(_, lens)| lens.label == label)
+            .map(|(idx, _)| idx)
+        {
+            self.lens_slots.remove(idx);
+        }
+    }
+
+    fn insert_lens(&mut self, lens: Lens) {
+        if let Some(idx) = self
+            .lens_slots
+            .iter()
+            .enumerate()
+            .find(|(_, l)| l.label == lens.label)
+            .map(|(idx, _)| idx)
+        {
+            self.lens_slots[idx] = lens;
+        } else {
+            self.lens_slots.push(lens);
+        }
+    }
+}
+
+/// Performs `step` on the box it targets.
+pub fn apply(step: &Step, b: &mut LensBox) {
+    match step.operation {
+        Operation::Remove => b.remove_lens(&step.label),
+        Operation::Insert(focal_length) => b.insert_lens(Lens {
+            label: step.label.clone(),
+            focal_length,
+        }),
+    }
+}
+
+/// What a step does to the box of its label.
+pub enum Operation {
+    /// Takes the lens with the label out, if present.
+    Remove,
+    /// Puts in a lens of this focal length, replacing one with the same label.
+    Insert(u8),
+}
+
+/// Initialization sequence step such as `rn=1` or `cm-`.
+pub struct Step {
+    pub label: String,
+    pub operation: Operation,
+}
+
+impl Step {
+    /// Parses a step such as `rn=1`.
+    pub fn parse(s: &str) -> IResult<&str, Self> {
+        map(
+            pair(
+                alpha1::<&str, _>,
+                alt((pair(char('-'), success(0)), pair(char('='), u8))),
+            ),
+            |(l, op)| Self {
+                label: l.to_string(),
+                operation: match op {
+                    ('-', _) => Operation::Remove,
+                    ('=', v) => Operation::Insert(v),
+                    _ => unreachable!(),
+                },
+            },
+        )(s)
+    }
+
+    /// Box targeted by the step: the hash of its label.
+    pub fn box_number(&self) -> usize {
+        hash(&self.label) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day15, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day15, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day15, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day15, part2))
+    }
+}
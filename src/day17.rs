@@ -0,0 +1,190 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+pub fn part1(input: &str) -> String {
+    HeatMap::parse(input).minimum_heat_loss().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    HeatMap::parse(input).minimum_heat_loss_ultra().to_string()
+}
+
+/// City blocks with their heat loss.
+pub struct HeatMap {
+    tiles: Vec<Vec<u32>>,
+}
+
+impl HeatMap {
+    /// Parses one row of digits per line.
+    pub fn parse(input: &str) -> Self {
+        let tiles = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|ch| ch.to_digit(10).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Self { tiles }
+    }
+
+    fn width(&self) -> usize {
+        self.tiles[0].len()
+    }
+
+    fn height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    fn neightbours(&self, pos: Position) -> impl Iterator<Item = (Position, Direction)> {
+        [
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+        ]
+        .iter()
+        .filter_map(move |&dir| self.step(pos, dir).map(|pos| (pos, dir)))
+        .collect::<Vec<_>>()
+        .into_iter()
+    }
+
+    fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        match dir {
+            Direction::Up if pos.y > 0 => Some(Position::new(pos.x, pos.y - 1)),
+            Direction::Down if pos.y < self.height() - 1 => Some(Position::new(pos.x, pos.y + 1)),
+            Direction::Left if pos.x > 0 => Some(Position::new(pos.x - 1, pos.y)),
+            Direction::Right if pos.x < self.width() - 1 => Some(Position::new(pos.x + 1, pos.y)),
+            _ => None,
+        }
+    }
+
+    fn heat(&self, pos: Position) -> u32 {
+        self.tiles[pos.y][pos.x]
+    }
+
+    /// Minimum heat loss for an ultra crucible, moving 4 to 10 blocks straight.
+    pub fn minimum_heat_loss_ultra(&self) -> u32 {
+        let mut distances = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((0, Position::new(0, 0), Direction::Right, 0)));
+        while let Some(Reverse((dist, pos, dir, sdir))) = queue.pop() {
+            let key = (pos, dir, sdir);
+            if distances.contains_key(&key) {
+                continue;
+            }
+            distances.insert(key, dist);
+            for (npos, ndir) in self.neightbours(pos).filter(|(_, d)| d.opposite() != dir) {
+                let nsdir = if ndir != dir { 1 } else { sdir + 1 };
+
+                if nsdir <= 10 && (dir == ndir || sdir >= 4 || sdir == 0) {
+                    let cost = self.heat(npos);
+                    queue.push(Reverse((dist + cost, npos, ndir, nsdir)));
+                }
+            }
+        }
+
+        distances
+            .iter()
+            .filter(|((pos, _, sdir), _)| {
+                pos.x == self.width() - 1 && pos.y == self.height() - 1 && *sdir >= 4
+            })
+            .map(|(_, d)| *d)
+            .min()
+            .unwrap()
+    }
+
+    /// Minimum heat loss for a crucible moving at most 3 blocks straight.
+    pub fn minimum_heat_loss(&self) -> u32 {
+        let mut distances = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((0, Position::new(0, 0), Direction::Right, 0)));
+        while let Some(Reverse((dist, pos, dir, sdir))) = queue.pop() {
+            let key = (pos, dir, sdir);
+            if distances.contains_key(&key) {
+                continue;
+            }
+            distances.insert(key, dist);
+            for (npos, ndir) in self.neightbours(pos).filter(|(_, d)| d.opposite() != dir) {
+                let nsdir = if ndir != dir { 1 } else { sdir + 1 };
+
+                if nsdir <= 3 {
+                    let cost = self.heat(npos);
+                    queue.push(Reverse((dist + cost, npos, ndir, nsdir)));
+                }
+            }
+        }
+
+        distances
+            .iter()
+            .filter(|((pos, _, _), _)| pos.x == self.width() - 1 && pos.y == self.height() - 1)
+            .map(|(_, d)| *d)
+            .min()
+            .unwrap()
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day17, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day17, part1))
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        let input = example!(day17, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day17, part2))
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        let input = example!(day17, part2_ex2);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day17, part2_ex2))
+    }
+}
@@ -0,0 +1,147 @@
+use nom::{
+    branch::alt,
+    character::complete::u128,
+    character::complete::{char, hex_digit1, space1},
+    combinator::map,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+pub fn part1(input: &str) -> String {
+    let steps = parse_input(input);
+    let p = steps.iter().map(|s| s.length).sum::<u128>();
+    let a = area(&steps);
+
+    (a + p / 2 + 1).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let steps = parse_input(input)
+        .iter()
+        .map(|x| x.decode())
+        .collect::<Vec<_>>();
+    let p = steps.iter().map(|s| s.length).sum::<u128>();
+    let a = area(&steps);
+
+    (a + p / 2 + 1).to_string()
+}
+
+/// Area enclosed by the centre line of the dig plan (shoelace formula).
+pub fn area(steps: &[DigStep]) -> u128 {
+    let mut a = 0i128;
+    let mut y = 0i128;
+    for step in steps {
+        match step.direction {
+            Direction::Right => a += y * step.length as i128,
+            Direction::Left => a -= y * step.length as i128,
+            Direction::Down => y -= step.length as i128,
+            Direction::Up => y += step.length as i128,
+        }
+    }
+    a as u128
+}
+
+/// Parses one dig step per line.
+pub fn parse_input(input: &str) -> Vec<DigStep> {
+    input
+        .lines()
+        .map(|line| DigStep::parse(line).unwrap().1)
+        .collect()
+}
+
+#[derive(Debug)]
+/// Dig plan instruction.
+pub struct DigStep {
+    pub direction: Direction,
+    pub length: u128,
+    pub color: String,
+}
+
+impl DigStep {
+    /// Instruction encoded in the colour, as in part 2.
+    pub fn decode(&self) -> Self {
+        let len = u128::from_str_radix(&self.color[0..self.color.len() - 1], 16).unwrap();
+        let dir = match self.color.chars().last().unwrap() {
+            '0' => Direction::Right,
+            '1' => Direction::Down,
+            '2' => Direction::Left,
+            '3' => Direction::Up,
+            _ => unreachable!(),
+        };
+
+        Self {
+            direction: dir,
+            length: len,
+            color: self.color.clone(),
+        }
+    }
+}
+
+fn color(input: &str) -> IResult<&str, &str> {
+    preceded(char('#'), hex_digit1)(input)
+}
+
+impl DigStep {
+    /// Parses an `R 6 (#70c710)` line.
+    pub fn parse(line: &str) -> IResult<&str, Self> {
+        map(
+            tuple((
+                Direction::parse,
+                delimited(space1, u128, space1),
+                delimited(char('('), color, char(')')),
+            )),
+            |(direction, length, color)| Self {
+                direction,
+                length,
+                color: color.to_string(),
+            },
+        )(line)
+    }
+}
+
+/// Direction to dig in.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            alt((char('R'), char('L'), char('U'), char('D'))),
+            |c| match c {
+                'R' => Self::Right,
+                'L' => Self::Left,
+                'U' => Self::Up,
+                'D' => Self::Down,
+                _ => unreachable!(),
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(day18, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day18, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(day18, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(day18, part2))
+    }
+}
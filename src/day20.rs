@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::alpha1,
+    character::complete::char,
+    combinator::map,
+    multi::separated_list1,
+    sequence::{pair, separated_pair},
+    IResult,
+};
+
+pub fn part1(input: &str) -> String {
+    let mut network = input
+        .lines()
+        .map(|line| parse_input(line).unwrap().1)
+        .collect::<Vec<_>>();
+    for i in 0..network.len() {
+        let name = network[i].name;
+        network[i].inputs = network
+            .iter_mut()
+            .filter_map(|d| d.outputs.contains(&name).then_some(d.name))
+            .collect();
+    }
+
+    let mut modules = network
+        .iter()
+        .map(|d| Module::from_descriptor(d))
+        .collect::<Vec<_>>();
+
+    let mut processed = Vec::new();
+
+    for _ in 0..1000 {
+        let mut outputs: VecDeque<_> = [Pulse::new(
+            "button".into(),
+            "broadcaster".into(),
+            PulseValue::Low,
+        )]
+        .into();
+        while let Some(pulse) = outputs.pop_front() {
+            if let Some(module) = modules.iter_mut().find(|m| m.name == pulse.dst) {
+                module.process(&pulse, &mut outputs);
+            }
+            processed.push(pulse);
+        }
+    }
+
+    let (lo, hi) = processed.iter().fold((0, 0), |(lo, hi), x| match x.value {
+        PulseValue::High => (lo, hi + 1),
+        PulseValue::Low => (lo + 1, hi),
+    });
+
+    (lo * hi).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut network = input
+        .lines()
+        .map(|line| parse_input(line).unwrap().1)
+        .collect::<Vec<_>>();
+    for i in 0..network.len() {
+        let name = network[i].name;
+        network[i].inputs = network
+            .iter_mut()
+            .filter_map(|d| d.outputs.contains(&name).then_some(d.name))
+            .collect();
+    }
+
+    let mut modules = network
+        .iter()
+        .map(|d| Module::from_descriptor(d))
+        .collect::<Vec<_>>();
+
+    //3739 4001 3943 3821
+
+    let mut counter = 0;
+    loop {
+        counter += 1;
+        let mut outputs: VecDeque<_> = [Pulse::new(
+            "button".into(),
+            "broadcaster".into(),
+            PulseValue::Low,
+        )]
+        .into();
+        while let Some(pulse) = outputs.pop_front() {
+            if pulse.value == PulseValue::High && pulse.src == "rv" {
+                return counter.to_string();
+            }
+
+            if let Some(module) = modules.iter_mut().find(|m| m.name == pulse.dst) {
+                module.process(&pulse, &mut outputs);
+            }
+        }
+    }
+}
+
+/// Parses a `name -> outputs` line; inputs are filled in once the whole network is known.
+pub fn parse_input(input: &str) -> IResult<&str, ModuleDescriptor<'_>> {
+    map(
+        separated_pair(
+            alt((
+                map(alpha1, |name| (None, name)),
+                map(pair(alt((char('%'), char('&'))), alpha1), |(t, name)| {
+                    (Some(t), name)
+                }),
+            )),
+            tag(" -> "),
+            separated_list1(tag(", "), alpha1),
+        ),
+        |((class, name), outputs)| ModuleDescriptor {
+            class,
+            name,
+            outputs,
+            inputs: Vec::new(),
+        },
+    )(input)
+}
+
+/// Module as configured: its type prefix (`%`, `&` or none) and connections.
+pub struct ModuleDescriptor<'a> {
+    pub class: Option<char>,
+    pub name: &'a str,
+    pub outputs: Vec<&'a str>,
+    pub inputs: Vec<&'a str>,
+}
+
+/// Level of a pulse.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PulseValue {
+    High,
+    Low,
+}
+
+/// Pulse sent from one module to another.
+#[derive(Debug)]
+pub struct Pulse {
+    pub src: String,
+    pub dst: String,
+    pub value: PulseValue,
+}
+
+impl Pulse {
+    /// Pulse of `value` from `src` to `dst`.
+    pub fn new(src: String, dst: String, value: PulseValue) -> Self {
+        Self { src, dst, value }
+    }
+}
+
+/// Behaviour of a module and what it remembers.
+pub enum ModuleState<'a> {
+    /// Repeats every pulse to all outputs.
+    Broadcaster,
+    /// Toggles on low pulses and sends its new state.
+    FlipFlop { enabled: bool },
+    /// Sends low when the last pulse from every input was high, high otherwise.
+    Conjunction { memory: Vec<(&'a str, PulseValue)> },
+}
+
+/// Communication module with its current state.
+pub struct Module<'a> {
+    pub name: &'a str,
+    pub state: ModuleState<'a>,
+    pub outputs: Vec<&'a str>,
+}
+
+impl<'a> Module<'a> {
+    /// Module in its initial state: flip-flops off, conjunctions remembering low.
+    pub fn from_descriptor(descriptor: &'a ModuleDescriptor) -> Self {
+        let state = match descriptor.class {
+            Some('%') => ModuleState::FlipFlop { enabled: false },
+            Some('&') => ModuleState::Conjunction {
+                memory: descriptor
+                    .inputs
+                    .iter()
+                    .map(|&input| (input, PulseValue::Low))
+                    .collect(),
+            },
+            None => ModuleState::Broadcaster,
+            _ => unreachable!(),
+        };
+        Self {
+            name: descriptor.name,
+            state,
+            outputs: descriptor.outputs.clone(),
+        }
+    }
+
+    /// Handles `pulse`, queueing the pulses the module sends in response.
+    pub fn process(&mut self, pulse: &Pulse, output: &mut VecDeque<Pulse>) {
+        match &mut self.state {
+            ModuleState::Broadcaster => {
+                self.broadcast(output, pulse.value);
+            }
+            ModuleState::FlipFlop { enabled } if pulse.value == PulseValue::Low => {
+                *enabled = !*enabled;
+                let out_value = if *enabled {
+                    PulseValue::High
+                } else {
+                    PulseValue::Low
+                };
+                self.broadcast(output, out_value);
+            }
+            ModuleState::Conjunction { memory } => {
+                memory.iter_mut().find(|m| m.0 == pulse.src).unwrap().1 = pulse.value;
+                if memory.iter().all(|x| x.1 == PulseValue::High) {
+                    self.broadcast(output, PulseValue::Low);
+                } else {
+                    self.broadcast(output, PulseValue::High);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn broadcast(&self, output: &'a mut VecDeque<Pulse>, pulse_value: PulseValue) {
+        for dst in &self.outputs {
+            output.push_back(Pulse::new(
+                self.name.to_string(),
+                dst.to_string(),
+                pulse_value,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1_ex1() {
+        let input = example!(day20, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day20, part1))
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let input = example!(day20, part1_ex2);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(day20, part1_ex2))
+    }
+}
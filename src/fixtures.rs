@@ -0,0 +1,177 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Input of the puzzle example stored as `fixtures/<day>/<name>.in`.
+#[macro_export]
+macro_rules! example {
+    ($day:ident, $name:ident) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/",
+            stringify!($day),
+            "/",
+            stringify!($name),
+            ".in"
+        ))
+        .trim_end_matches('\n')
+    };
+}
+
+/// Expected answer stored as `fixtures/<day>/<name>.out`.
+#[macro_export]
+macro_rules! answer {
+    ($day:ident, $name:ident) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/",
+            stringify!($day),
+            "/",
+            stringify!($name),
+            ".out"
+        ))
+        .trim_end()
+    };
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub part: usize,
+    /// `None` when the part reuses the example of the previous part.
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Extracts one example per puzzle part from a saved puzzle description page.
+///
+/// The answer of a part is the last emphasised `<code><em>` in its article, and
+/// its input is the last `<pre><code>` block in the article before that answer.
+pub fn extract(html: &str) -> Vec<Example> {
+    articles(html)
+        .enumerate()
+        .map(|(i, article)| {
+            let answer = find_last(article, "<code><em>", "</em></code>");
+            let before_answer = match answer {
+                Some((start, _)) => &article[..start],
+                None => article,
+            };
+            Example {
+                part: i + 1,
+                input: find_last(before_answer, "<pre><code>", "</code></pre>")
+                    .map(|(_, block)| decode(block).trim_end_matches('\n').to_string()),
+                answer: answer.map(|(_, answer)| decode(answer)),
+            }
+        })
+        .collect()
+}
+
+/// Writes `part<N>.in` and `part<N>.out` for every example into `dir`.
+pub fn write(dir: &Path, examples: &[Example]) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for example in examples {
+        let files = [
+            ("in", example.input.as_ref()),
+            ("out", example.answer.as_ref()),
+        ];
+        for (ext, content) in files {
+            if let Some(content) = content {
+                let path = dir.join(format!("part{}.{}", example.part, ext));
+                fs::write(&path, content)?;
+                written.push(path);
+            }
+        }
+    }
+    Ok(written)
+}
+
+fn articles(html: &str) -> impl Iterator<Item = &str> {
+    html.split("<article")
+        .skip(1)
+        .map(|rest| rest.split("</article>").next().unwrap())
+}
+
+fn find_last<'a>(text: &'a str, open: &str, close: &str) -> Option<(usize, &'a str)> {
+    let start = text.rfind(open)?;
+    let body = &text[start + open.len()..];
+    let end = body.find(close)?;
+    Some((start, &body[..end]))
+}
+
+fn decode(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::Example;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 5: Test ---</h2>
+<p>For example:</p>
+<pre><code>a -&gt; b
+<em>c</em> &amp; d
+</code></pre>
+<p>Here the answer is <code>7</code>, so the total is <code><em>42</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now the same example gives <code><em>99</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = super::extract(PAGE);
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    part: 1,
+                    input: Some("a -> b\nc & d".to_string()),
+                    answer: Some("42".to_string()),
+                },
+                Example {
+                    part: 2,
+                    input: None,
+                    answer: Some("99".to_string()),
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let written = super::write(dir.path(), &super::extract(PAGE)).unwrap();
+
+        assert_eq!(written.len(), 3);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("part1.in")).unwrap(),
+            "a -> b\nc & d"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("part2.out")).unwrap(),
+            "99"
+        );
+        assert!(!dir.path().join("part2.in").exists());
+    }
+}
@@ -0,0 +1,264 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/tarasov65536/advent-of-code-2023 aoc2023/",
+    env!("CARGO_PKG_VERSION")
+);
+
+const LAST_REQUEST_FILE: &str = "last-request";
+
+#[derive(Debug)]
+pub enum Error {
+    NoSession,
+    Http(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoSession => write!(f, "no session cookie, set AOC_SESSION"),
+            Error::Http(err) => write!(f, "request failed: {}", err),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+pub trait Fetcher {
+    fn fetch(&self, url: &str, session: &str) -> Result<String, Error>;
+}
+
+pub struct HttpFetcher {
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub fn new() -> Self {
+        let config = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build();
+        Self {
+            agent: config.into(),
+        }
+    }
+}
+
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, url: &str, session: &str) -> Result<String, Error> {
+        self.agent
+            .get(url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| Error::Http(err.to_string()))
+    }
+}
+
+/// Puzzle inputs cached on disk, one directory per account.
+///
+/// An input is downloaded at most once: after the first successful fetch the
+/// cached file is always used. Downloads are spaced at least `min_interval`
+/// apart, also across processes sharing the same cache root.
+pub struct InputStore<F = HttpFetcher> {
+    root: PathBuf,
+    session: String,
+    fetcher: F,
+    base_url: String,
+    min_interval: Duration,
+}
+
+impl InputStore<HttpFetcher> {
+    /// Store for the session in `AOC_SESSION`, cached under the user cache directory.
+    pub fn from_env() -> Result<Self, Error> {
+        let session = env::var("AOC_SESSION").map_err(|_| Error::NoSession)?;
+        Ok(Self::new(cache_root(), session.trim(), HttpFetcher::new()))
+    }
+}
+
+impl<F: Fetcher> InputStore<F> {
+    pub fn new(root: impl Into<PathBuf>, session: &str, fetcher: F) -> Self {
+        Self {
+            root: root.into(),
+            session: session.to_string(),
+            fetcher,
+            base_url: BASE_URL.to_string(),
+            min_interval: Duration::from_secs(5),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn account(&self) -> String {
+        format!("{:016x}", fnv1a(self.session.as_bytes()))
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root
+            .join(self.account())
+            .join(format!("day{:02}.in", day))
+    }
+
+    pub fn get(&self, day: u8) -> Result<String, Error> {
+        let path = self.path(day);
+        if path.exists() {
+            return Ok(fs::read_to_string(path)?);
+        }
+
+        self.wait_for_slot()?;
+        let url = format!("{}/2023/day/{}/input", self.base_url, day);
+        let fetched = self.fetcher.fetch(&url, &self.session);
+        self.record_request()?;
+        let input = fetched?;
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        let tmp = path.with_extension("in.part");
+        fs::write(&tmp, &input)?;
+        fs::rename(tmp, &path)?;
+        Ok(input)
+    }
+
+    fn wait_for_slot(&self) -> io::Result<()> {
+        let last = match fs::read_to_string(self.root.join(LAST_REQUEST_FILE)) {
+            Ok(stamp) => stamp.trim().parse::<u64>().ok(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+        if let Some(last) = last {
+            let elapsed = Duration::from_millis(now_millis().saturating_sub(last));
+            if let Some(remaining) = self.min_interval.checked_sub(elapsed) {
+                thread::sleep(remaining);
+            }
+        }
+        Ok(())
+    }
+
+    fn record_request(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.root)?;
+        fs::write(self.root.join(LAST_REQUEST_FILE), now_millis().to_string())
+    }
+}
+
+/// Loads the input for `day`, preferring the account store when `AOC_SESSION`
+/// is set and falling back to the input committed next to the binary.
+pub fn load(day: u8) -> String {
+    match InputStore::from_env() {
+        Ok(store) => store
+            .get(day)
+            .unwrap_or_else(|err| panic!("failed to load input for day {}: {}", day, err)),
+        Err(_) => {
+            let path = committed_path(day);
+            fs::read_to_string(&path).unwrap_or_else(|err| {
+                panic!(
+                    "no input for day {}: set AOC_SESSION or add {} ({})",
+                    day,
+                    path.display(),
+                    err
+                )
+            })
+        }
+    }
+}
+
+pub fn committed_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("bin")
+        .join(format!("day{:02}.in", day))
+}
+
+fn cache_root() -> PathBuf {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("aoc2023")
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    use super::{Error, Fetcher, InputStore};
+
+    struct CountingFetcher(Cell<usize>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, url: &str, session: &str) -> Result<String, Error> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("{} {}\n", url, session))
+        }
+    }
+
+    #[test]
+    fn test_never_refetches() {
+        let root = tempfile::tempdir().unwrap();
+        let store = InputStore::new(root.path(), "abc", CountingFetcher(Cell::new(0)))
+            .with_base_url("http://localhost/")
+            .with_min_interval(Duration::ZERO);
+
+        let first = store.get(4).unwrap();
+        let second = store.get(4).unwrap();
+
+        assert_eq!(first, "http://localhost/2023/day/4/input abc\n");
+        assert_eq!(first, second);
+        assert_eq!(store.fetcher.0.get(), 1);
+    }
+
+    #[test]
+    fn test_accounts_are_separate() {
+        let root = tempfile::tempdir().unwrap();
+        let a = InputStore::new(root.path(), "a", CountingFetcher(Cell::new(0)));
+        let b = InputStore::new(root.path(), "b", CountingFetcher(Cell::new(0)));
+
+        assert_ne!(a.path(1), b.path(1));
+        assert_eq!(a.path(1).parent(), a.path(2).parent());
+    }
+}
@@ -0,0 +1,110 @@
+// Generated by `aoc new`, do not edit by hand.
+
+pub struct Day {
+    pub number: u8,
+    pub part1: fn(&str) -> String,
+    pub part2: fn(&str) -> String,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        part1: crate::day01::part1,
+        part2: crate::day01::part2,
+    },
+    Day {
+        number: 2,
+        part1: crate::day02::part1,
+        part2: crate::day02::part2,
+    },
+    Day {
+        number: 3,
+        part1: crate::day03::part1,
+        part2: crate::day03::part2,
+    },
+    Day {
+        number: 4,
+        part1: crate::day04::part1,
+        part2: crate::day04::part2,
+    },
+    Day {
+        number: 5,
+        part1: crate::day05::part1,
+        part2: crate::day05::part2,
+    },
+    Day {
+        number: 6,
+        part1: crate::day06::part1,
+        part2: crate::day06::part2,
+    },
+    Day {
+        number: 7,
+        part1: crate::day07::part1,
+        part2: crate::day07::part2,
+    },
+    Day {
+        number: 8,
+        part1: crate::day08::part1,
+        part2: crate::day08::part2,
+    },
+    Day {
+        number: 9,
+        part1: crate::day09::part1,
+        part2: crate::day09::part2,
+    },
+    Day {
+        number: 10,
+        part1: crate::day10::part1,
+        part2: crate::day10::part2,
+    },
+    Day {
+        number: 11,
+        part1: crate::day11::part1,
+        part2: crate::day11::part2,
+    },
+    Day {
+        number: 12,
+        part1: crate::day12::part1,
+        part2: crate::day12::part2,
+    },
+    Day {
+        number: 13,
+        part1: crate::day13::part1,
+        part2: crate::day13::part2,
+    },
+    Day {
+        number: 14,
+        part1: crate::day14::part1,
+        part2: crate::day14::part2,
+    },
+    Day {
+        number: 15,
+        part1: crate::day15::part1,
+        part2: crate::day15::part2,
+    },
+    Day {
+        number: 16,
+        part1: crate::day16::part1,
+        part2: crate::day16::part2,
+    },
+    Day {
+        number: 17,
+        part1: crate::day17::part1,
+        part2: crate::day17::part2,
+    },
+    Day {
+        number: 18,
+        part1: crate::day18::part1,
+        part2: crate::day18::part2,
+    },
+    Day {
+        number: 19,
+        part1: crate::day19::part1,
+        part2: crate::day19::part2,
+    },
+    Day {
+        number: 20,
+        part1: crate::day20::part1,
+        part2: crate::day20::part2,
+    },
+];
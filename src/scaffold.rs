@@ -0,0 +1,270 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MODULE_TEMPLATE: &str = r#"pub fn part1(_input: &str) -> String {
+    todo!()
+}
+
+pub fn part2(_input: &str) -> String {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answer, example};
+
+    #[test]
+    fn test_part1() {
+        let input = example!(dayNN, part1);
+
+        let output = super::part1(input);
+
+        assert_eq!(output, answer!(dayNN, part1))
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = example!(dayNN, part1);
+
+        let output = super::part2(input);
+
+        assert_eq!(output, answer!(dayNN, part2))
+    }
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use aoc2023::dayNN::{part1, part2};
+
+fn main() {
+    let input = aoc2023::input::load(N);
+
+    let part1_out = part1(&input);
+    let part2_out = part2(&input);
+
+    println!("Part1: {}", part1_out);
+    println!("Part2: {}", part2_out);
+}
+"#;
+
+const FIXTURE_SLOTS: [&str; 3] = ["part1.in", "part1.out", "part2.out"];
+
+const REGISTRY_HEADER: &str = "// Generated by `aoc new`, do not edit by hand.
+
+pub struct Day {
+    pub number: u8,
+    pub part1: fn(&str) -> String,
+    pub part2: fn(&str) -> String,
+}
+
+";
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidDay(u8),
+    AlreadyExists(PathBuf),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidDay(day) => write!(f, "day {} is not in 1..=25", day),
+            Error::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+pub struct Scaffold {
+    root: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Creates the solution module, its binary and empty example fixtures for
+    /// `day`, then declares the module and re-registers all days.
+    /// Returns the paths that were written.
+    pub fn create_day(&self, day: u8) -> Result<Vec<PathBuf>, Error> {
+        if !(1..=25).contains(&day) {
+            return Err(Error::InvalidDay(day));
+        }
+
+        let src = self.root.join("src");
+        let module = src.join(format!("day{:02}.rs", day));
+        let bin = src.join("bin").join(format!("day{:02}.rs", day));
+        if let Some(existing) = [&module, &bin].into_iter().find(|p| p.exists()) {
+            return Err(Error::AlreadyExists(existing.clone()));
+        }
+
+        fs::create_dir_all(src.join("bin"))?;
+        fs::write(&module, render(MODULE_TEMPLATE, day))?;
+        fs::write(&bin, render(BIN_TEMPLATE, day))?;
+        let mut written = vec![module, bin];
+
+        let fixtures = self.root.join("fixtures").join(format!("day{:02}", day));
+        fs::create_dir_all(&fixtures)?;
+        for slot in FIXTURE_SLOTS.map(|slot| fixtures.join(slot)) {
+            if !slot.exists() {
+                fs::write(&slot, "")?;
+                written.push(slot);
+            }
+        }
+
+        let lib = src.join("lib.rs");
+        let declarations = fs::read_to_string(&lib)?;
+        fs::write(&lib, declare_module(&declarations, day))?;
+        written.push(lib);
+
+        let registry = src.join("registry.rs");
+        fs::write(&registry, render_registry(&scan_days(&src)?))?;
+        written.push(registry);
+
+        Ok(written)
+    }
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("dayNN", &format!("day{:02}", day))
+        .replace("(N)", &format!("({})", day))
+}
+
+/// Adds `pub mod dayNN;` to the module declarations, keeping them sorted.
+fn declare_module(lib: &str, day: u8) -> String {
+    let declaration = format!("pub mod day{:02};", day);
+    let mut lines = lib.lines().collect::<Vec<_>>();
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > declaration.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod "))
+                .map(|i| i + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(position, &declaration);
+    lines.join("\n") + "\n"
+}
+
+fn render_registry(days: &[u8]) -> String {
+    let mut out = format!("{}pub const DAYS: &[Day] = &[\n", REGISTRY_HEADER);
+    for day in days {
+        out.push_str(&format!(
+            "    Day {{
+        number: {day},
+        part1: crate::day{day:02}::part1,
+        part2: crate::day{day:02}::part2,
+    }},
+",
+            day = day
+        ));
+    }
+    out.push_str("];\n");
+    out
+}
+
+fn scan_days(src: &Path) -> io::Result<Vec<u8>> {
+    let mut days = fs::read_dir(src)?
+        .map(|entry| entry.map(|e| e.file_name()))
+        .filter_map(|name| match name {
+            Ok(name) => name
+                .to_str()
+                .and_then(|n| n.strip_prefix("day"))
+                .and_then(|n| n.strip_suffix(".rs"))
+                .and_then(|n| n.parse().ok())
+                .map(Ok),
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<io::Result<Vec<u8>>>()?;
+    days.sort_unstable();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{Error, Scaffold};
+
+    fn project() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let src = root.path().join("src");
+        fs::create_dir_all(src.join("bin")).unwrap();
+        fs::write(src.join("day01.rs"), "").unwrap();
+        fs::write(src.join("day04.rs"), "").unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            "pub mod day01;\npub mod day04;\npub mod input;\n",
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = project();
+        let src = root.path().join("src");
+
+        Scaffold::new(root.path()).create_day(3).unwrap();
+
+        let module = fs::read_to_string(src.join("day03.rs")).unwrap();
+        assert!(module.contains("example!(day03, part1)"));
+        assert!(module.contains("answer!(day03, part2)"));
+        let bin = fs::read_to_string(src.join("bin").join("day03.rs")).unwrap();
+        assert!(bin.contains("use aoc2023::day03::{part1, part2};"));
+        assert!(bin.contains("aoc2023::input::load(3)"));
+        assert!(root.path().join("fixtures/day03/part1.in").exists());
+        assert_eq!(
+            fs::read_to_string(src.join("lib.rs")).unwrap(),
+            "pub mod day01;\npub mod day03;\npub mod day04;\npub mod input;\n"
+        );
+        let registry = fs::read_to_string(src.join("registry.rs")).unwrap();
+        assert!(registry.contains("part2: crate::day03::part2,"));
+        assert_eq!(registry.matches("part1: crate::").count(), 3);
+    }
+
+    #[test]
+    fn test_declare_module() {
+        assert_eq!(
+            super::declare_module("pub mod day01;\npub mod day02;\n", 25),
+            "pub mod day01;\npub mod day02;\npub mod day25;\n"
+        );
+        assert_eq!(super::declare_module("", 7), "pub mod day07;\n");
+    }
+
+    #[test]
+    fn test_refuses_to_overwrite() {
+        let root = project();
+        let bin = root.path().join("src").join("bin").join("day07.rs");
+        fs::write(&bin, "fn main() {}").unwrap();
+
+        let result = Scaffold::new(root.path()).create_day(7);
+
+        assert!(matches!(result, Err(Error::AlreadyExists(_))));
+        assert_eq!(fs::read_to_string(&bin).unwrap(), "fn main() {}");
+        assert!(!root.path().join("src").join("day07.rs").exists());
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        let root = tempfile::tempdir().unwrap();
+
+        let result = Scaffold::new(root.path()).create_day(26);
+
+        assert!(matches!(result, Err(Error::InvalidDay(26))));
+    }
+}
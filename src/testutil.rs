@@ -0,0 +1,37 @@
+//! Helpers shared by tests and benches.
+
+/// Xorshift generator for reproducible pseudo-random test and bench data.
+pub struct XorShift(u64);
+
+impl XorShift {
+    /// A zero `seed`, which xorshift would never leave, is replaced by a
+    /// fixed non-zero one.
+    pub fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 0x2545f4914f6cdd1d } else { seed })
+    }
+
+    /// Next value in `0..n`, or any `u64` when `n` is 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        match n {
+            0 => self.0,
+            n => self.0 % n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XorShift;
+
+    #[test]
+    fn test_xorshift() {
+        let mut zero = XorShift::new(0);
+        let mut rng = XorShift::new(7);
+
+        assert_ne!(zero.below(0), 0);
+        assert!((0..100).all(|_| rng.below(10) < 10));
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::input::{self, InputStore};
+
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Debug)]
+pub enum Error {
+    Notify(notify::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Notify(err) => write!(f, "{}", err),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<notify::Error> for Error {
+    fn from(err: notify::Error) -> Self {
+        Error::Notify(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Answers printed by a day binary as `Part1: ...` and `Part2: ...` lines.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(output: &str) -> Self {
+        let mut answers = Self::default();
+        for line in output.lines() {
+            if let Some(value) = line.strip_prefix("Part1: ") {
+                answers.part1 = Some(value.trim().to_string());
+            } else if let Some(value) = line.strip_prefix("Part2: ") {
+                answers.part2 = Some(value.trim().to_string());
+            }
+        }
+        answers
+    }
+
+    /// Describes how every answer changed compared to `previous`.
+    pub fn diff(&self, previous: Option<&Answers>) -> Vec<String> {
+        let parts = [
+            ("Part1", &self.part1, previous.map(|p| &p.part1)),
+            ("Part2", &self.part2, previous.map(|p| &p.part2)),
+        ];
+        parts
+            .into_iter()
+            .map(|(name, current, previous)| {
+                let current = current.as_deref().unwrap_or("-");
+                match previous {
+                    None => format!("{}: {}", name, current),
+                    Some(previous) => {
+                        let previous = previous.as_deref().unwrap_or("-");
+                        if previous == current {
+                            format!("{}: {} (unchanged)", name, current)
+                        } else {
+                            format!("{}: {} -> {}", name, previous, current)
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Re-runs the tests and the solution of `day` every time its source or
+/// input changes. Runs until the watcher fails.
+pub fn watch(root: &Path, day: u8) -> Result<(), Error> {
+    let watched = Watched::new(root, day);
+    // Input store and fixture directories may only be filled in later, by
+    // the first fetch or by `aoc fixtures`, so create them before watching.
+    if let Some(store) = &watched.store {
+        fs::create_dir_all(store)?;
+    }
+    fs::create_dir_all(&watched.fixtures)?;
+    let dirs = watched
+        .files
+        .iter()
+        .filter_map(|f| f.parent())
+        .chain(watched.store.as_deref())
+        .chain([watched.fixtures.as_path()])
+        .collect::<HashSet<_>>();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for dir in dirs.iter().filter(|d| d.exists()) {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+    for file in &watched.files {
+        println!("Watching {}", file.display());
+    }
+    println!("Watching {}/*.{{in,out}}", watched.fixtures.display());
+
+    let mut previous = None;
+    loop {
+        if let Some(answers) = run_once(root, day)? {
+            for line in answers.diff(previous.as_ref()) {
+                println!("{}", line);
+            }
+            previous = Some(answers);
+        }
+
+        wait_for_change(&rx, &watched)?;
+        println!();
+    }
+}
+
+fn wait_for_change(
+    rx: &mpsc::Receiver<notify::Result<Event>>,
+    watched: &Watched,
+) -> Result<(), Error> {
+    loop {
+        let event = rx.recv().map_err(|_| watcher_closed())??;
+        if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+            && event.paths.iter().any(|p| watched.matches(p))
+        {
+            break;
+        }
+    }
+    // Editors usually emit a burst of events per save.
+    while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+        event?;
+    }
+    Ok(())
+}
+
+/// Paths whose changes trigger a re-run of a day.
+struct Watched {
+    /// Sources and inputs of the day.
+    files: Vec<PathBuf>,
+    /// Directory of the account's input store, when one is configured.
+    store: Option<PathBuf>,
+    /// Every `.in` and `.out` file here counts, extra examples included.
+    fixtures: PathBuf,
+}
+
+impl Watched {
+    fn new(root: &Path, day: u8) -> Self {
+        let src = root.join("src");
+        let mut files = vec![
+            src.join(format!("day{:02}.rs", day)),
+            src.join("bin").join(format!("day{:02}.rs", day)),
+            input::committed_path(day),
+        ];
+        let mut store = None;
+        if let Ok(s) = InputStore::from_env() {
+            let path = s.path(day);
+            store = path.parent().map(Path::to_path_buf);
+            files.push(path);
+        }
+        Self {
+            files,
+            store,
+            fixtures: root.join("fixtures").join(format!("day{:02}", day)),
+        }
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        self.files.iter().any(|f| f == path)
+            || (path.parent() == Some(self.fixtures.as_path())
+                && matches!(
+                    path.extension().and_then(|e| e.to_str()),
+                    Some("in" | "out")
+                ))
+    }
+}
+
+/// Builds, tests and runs the day; returns its answers if all steps succeeded.
+fn run_once(root: &Path, day: u8) -> io::Result<Option<Answers>> {
+    let bin = format!("day{:02}", day);
+    let filter = format!("{}::", bin);
+    let steps = [
+        vec!["build", "-q", "--bin", &bin],
+        vec!["test", "-q", "--lib", "--", &filter],
+    ];
+    for step in steps {
+        let status = cargo(root).args(&step).status()?;
+        if !status.success() {
+            println!("cargo {} failed", step[0]);
+            return Ok(None);
+        }
+    }
+
+    let output = cargo(root)
+        .args(["run", "-q", "--bin", &bin])
+        .stderr(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        println!("{} failed", bin);
+        return Ok(None);
+    }
+    Ok(Some(Answers::parse(&String::from_utf8_lossy(
+        &output.stdout,
+    ))))
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+fn watcher_closed() -> Error {
+    Error::Io(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "file watcher stopped",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{Answers, Watched};
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("Part1: 142\nPart2: 281\n");
+
+        assert_eq!(
+            answers,
+            Answers {
+                part1: Some("142".to_string()),
+                part2: Some("281".to_string()),
+            }
+        )
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = Answers::parse("Part1: 142\nPart2: 281");
+        let current = Answers::parse("Part1: 142\nPart2: 280");
+
+        assert_eq!(current.diff(None), vec!["Part1: 142", "Part2: 280"]);
+        assert_eq!(
+            current.diff(Some(&previous)),
+            vec!["Part1: 142 (unchanged)", "Part2: 281 -> 280"]
+        );
+    }
+
+    #[test]
+    fn test_watched_fixtures() {
+        let root = Path::new("/tmp/aoc");
+        let watched = Watched::new(root, 7);
+        let fixtures = root.join("fixtures").join("day07");
+
+        assert!(watched.matches(&root.join("src").join("day07.rs")));
+        assert!(watched.matches(&fixtures.join("part1.in")));
+        assert!(watched.matches(&fixtures.join("part2_ex3.in")));
+        assert!(watched.matches(&fixtures.join("part1_ex2.out")));
+        assert!(!watched.matches(&fixtures.join("notes.txt")));
+        assert!(!watched.matches(&root.join("fixtures").join("day08").join("part1.in")));
+    }
+}
@@ -0,0 +1,137 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2023::input::{Error, HttpFetcher, InputStore};
+
+const SESSION: &str = "53616c7465645f5f";
+
+/// Minimal stand-in for the puzzle server: serves `/2023/day/N/input` to
+/// requests carrying the expected session cookie and counts every request.
+struct MockServer {
+    url: String,
+    hits: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let mut request_line = String::new();
+                let mut cookie = None;
+                let mut reader = BufReader::new(&stream);
+                reader.read_line(&mut request_line).unwrap();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("cookie") {
+                            cookie = Some(value.trim().to_string());
+                        }
+                    }
+                }
+
+                let day = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .and_then(|path| path.strip_prefix("/2023/day/"))
+                    .and_then(|path| path.strip_suffix("/input"))
+                    .map(str::to_string);
+                let (status, body) = match (day, cookie) {
+                    (Some(day), Some(cookie)) if cookie == format!("session={}", SESSION) => {
+                        ("200 OK", format!("input for day {}\n", day))
+                    }
+                    (Some(_), _) => ("400 Bad Request", "Puzzle inputs differ by user.\n".into()),
+                    _ => ("404 Not Found", "404 Not Found\n".into()),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        Self { url, hits }
+    }
+
+    fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+}
+
+fn store(server: &MockServer, root: &std::path::Path, session: &str) -> InputStore {
+    InputStore::new(root, session, HttpFetcher::new())
+        .with_base_url(&server.url)
+        .with_min_interval(Duration::ZERO)
+}
+
+#[test]
+fn test_fetches_once_and_caches() {
+    let server = MockServer::start();
+    let root = tempfile::tempdir().unwrap();
+    let store = store(&server, root.path(), SESSION);
+
+    assert_eq!(store.get(5).unwrap(), "input for day 5\n");
+    assert_eq!(store.get(5).unwrap(), "input for day 5\n");
+
+    assert_eq!(server.hits(), 1);
+    assert!(store.path(5).starts_with(root.path().join(store.account())));
+}
+
+#[test]
+fn test_cache_survives_new_store() {
+    let server = MockServer::start();
+    let root = tempfile::tempdir().unwrap();
+
+    store(&server, root.path(), SESSION).get(12).unwrap();
+    let input = store(&server, root.path(), SESSION).get(12).unwrap();
+
+    assert_eq!(input, "input for day 12\n");
+    assert_eq!(server.hits(), 1);
+}
+
+#[test]
+fn test_rejected_session_is_not_cached() {
+    let server = MockServer::start();
+    let root = tempfile::tempdir().unwrap();
+    let store = store(&server, root.path(), "expired");
+
+    assert!(matches!(store.get(1), Err(Error::Http(_))));
+    assert!(matches!(store.get(1), Err(Error::Http(_))));
+
+    assert_eq!(server.hits(), 2);
+    assert!(!store.path(1).exists());
+}
+
+#[test]
+fn test_requests_are_rate_limited() {
+    let server = MockServer::start();
+    let root = tempfile::tempdir().unwrap();
+    let store = store(&server, root.path(), SESSION).with_min_interval(Duration::from_millis(300));
+
+    let start = Instant::now();
+    store.get(1).unwrap();
+    store.get(2).unwrap();
+    store.get(2).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.hits(), 2);
+}